@@ -0,0 +1,369 @@
+//! A [`GlobalAlloc`] implementation over [`crate::syscalls::SyscallTable::SysMemMap`], available with the `alloc` feature
+//!
+//! This crate doesn't perform syscalls itself, so the allocator is generic over a [`MemBackend`] that performs the few syscalls it needs,
+//! usually implemented by the API or the libstd port.
+//!
+//! Small allocations are served from per size class slabs, freed blocks are kept in a free list per size class and reused,
+//! larger allocations get their own mapping surrounded by guard pages and are unmapped once freed,
+//! they are resized with [`crate::syscalls::SyscallTable::SysMemRemap`] if the backend supports it instead of being copied.
+//!
+//! ```ignore
+//! #[global_allocator]
+//! static ALLOCATOR: SafaAlloc<MyBackend> = SafaAlloc::new();
+//! ```
+use core::alloc::{GlobalAlloc, Layout};
+use core::cell::UnsafeCell;
+use core::marker::PhantomData;
+use core::ptr::{self, NonNull};
+use core::sync::atomic::{AtomicU32, Ordering};
+
+use crate::errors::ErrorStatus;
+use crate::mem::{MemMapFlags, MemRemapFlags, RawMemMapConfig};
+
+/// Performs the syscalls required by [`SafaAlloc`]
+///
+/// # Safety
+/// The allocator trusts the backend, [`MemBackend::mem_map`] must return memory that is valid, writable and not used by anything else
+/// until it is destroyed using [`MemBackend::destroy`].
+pub unsafe trait MemBackend {
+    /// Performs a [`crate::syscalls::SyscallTable::SysMemMap`] with the given config and flags, returns the Resource that tracks the mapping and the mapping's start address
+    fn mem_map(
+        config: &RawMemMapConfig,
+        flags: MemMapFlags,
+    ) -> Result<(u32, NonNull<u8>), ErrorStatus>;
+    /// Performs a [`crate::syscalls::SyscallTable::SysMemRemap`] on a Resource returned by [`MemBackend::mem_map`] with the given page count and flags,
+    /// returns the mapping's new start address
+    ///
+    /// Optional, by default errors with [`ErrorStatus::OperationNotSupported`] and the allocator falls back to mapping a new region and copying
+    fn mem_remap(
+        resource: u32,
+        page_count: usize,
+        flags: MemRemapFlags,
+    ) -> Result<NonNull<u8>, ErrorStatus> {
+        _ = (resource, page_count, flags);
+        Err(ErrorStatus::OperationNotSupported)
+    }
+    /// Destroys a Resource returned by [`MemBackend::mem_map`] using [`crate::syscalls::SyscallTable::SysRDestroy`], unmapping its memory
+    fn destroy(resource: u32);
+    /// Performs a [`crate::syscalls::SyscallTable::SysTFutWait`] on the given address without a timeout, spurious wake ups are fine
+    fn futex_wait(addr: &AtomicU32, expected: u32);
+    /// Performs a [`crate::syscalls::SyscallTable::SysTFutWake`] on the given address, waking up to `n` threads
+    fn futex_wake(addr: &AtomicU32, n: usize);
+    /// Returns the base page size, usually [`crate::mem::PageSizes::base`] from [`crate::process::AbiStructures::page_sizes`],
+    /// must be a power of two of at least [`MAX_SMALL_ALIGN`] bytes
+    fn page_size() -> usize;
+}
+
+/// The smallest size class in bytes, every allocation takes at least this much
+const MIN_CLASS_SIZE: usize = 16;
+const MIN_CLASS_SHIFT: u32 = MIN_CLASS_SIZE.trailing_zeros();
+/// The largest size class in bytes, larger allocations get their own mapping
+pub const MAX_SMALL_SIZE: usize = 32 * 1024;
+/// The largest alignment served from the size classes, allocations with a larger alignment get their own mapping
+pub const MAX_SMALL_ALIGN: usize = 4096;
+const CLASS_COUNT: usize = (MAX_SMALL_SIZE.trailing_zeros() - MIN_CLASS_SHIFT + 1) as usize;
+
+/// The minimum size of a single slab, a slab holds blocks of a single size class
+const MIN_SLAB_SIZE: usize = 64 * 1024;
+/// The minimum amount of blocks in a single slab
+const MIN_SLAB_BLOCKS: usize = 8;
+/// The amount of guard pages before and after each large allocation
+const LARGE_GUARD_PAGES: usize = 1;
+
+const MAP_FLAGS: MemMapFlags =
+    MemMapFlags::from_bits(MemMapFlags::WRITE.to_bits() | MemMapFlags::DISABLE_EXEC.to_bits());
+
+/// A lock built on top of the futex syscalls
+struct FutexLock<B: MemBackend> {
+    state: AtomicU32,
+    _backend: PhantomData<fn() -> B>,
+}
+
+impl<B: MemBackend> FutexLock<B> {
+    const UNLOCKED: u32 = 0;
+    const LOCKED: u32 = 1;
+    /// Locked and there may be threads waiting on the futex
+    const CONTENDED: u32 = 2;
+
+    const fn new() -> Self {
+        Self {
+            state: AtomicU32::new(Self::UNLOCKED),
+            _backend: PhantomData,
+        }
+    }
+
+    fn lock(&self) {
+        if self
+            .state
+            .compare_exchange(
+                Self::UNLOCKED,
+                Self::LOCKED,
+                Ordering::Acquire,
+                Ordering::Relaxed,
+            )
+            .is_ok()
+        {
+            return;
+        }
+
+        while self.state.swap(Self::CONTENDED, Ordering::Acquire) != Self::UNLOCKED {
+            B::futex_wait(&self.state, Self::CONTENDED);
+        }
+    }
+
+    fn unlock(&self) {
+        if self.state.swap(Self::UNLOCKED, Ordering::Release) == Self::CONTENDED {
+            B::futex_wake(&self.state, 1);
+        }
+    }
+}
+
+/// A freed block in a size class's free list
+struct FreeBlock {
+    next: *mut FreeBlock,
+}
+
+#[derive(Clone, Copy)]
+struct SizeClass {
+    free_list: *mut FreeBlock,
+    /// The next never used block in the current slab
+    cursor: usize,
+    slab_end: usize,
+}
+
+impl SizeClass {
+    const EMPTY: Self = Self {
+        free_list: ptr::null_mut(),
+        cursor: 0,
+        slab_end: 0,
+    };
+}
+
+/// Stored right before the pointer returned for a large allocation
+struct LargeHeader {
+    resource: u32,
+    /// The amount of pages in the mapping, not including the guard pages
+    page_count: usize,
+    /// The offset of the returned pointer from the start of the mapping
+    head: usize,
+}
+
+/// A thread safe [`GlobalAlloc`] over [`crate::syscalls::SyscallTable::SysMemMap`], see the [module level documentation](self)
+pub struct SafaAlloc<B: MemBackend> {
+    lock: FutexLock<B>,
+    classes: UnsafeCell<[SizeClass; CLASS_COUNT]>,
+}
+
+// the size classes are only accessed while holding the lock
+unsafe impl<B: MemBackend> Sync for SafaAlloc<B> {}
+
+impl<B: MemBackend> SafaAlloc<B> {
+    pub const fn new() -> Self {
+        Self {
+            lock: FutexLock::new(),
+            classes: UnsafeCell::new([SizeClass::EMPTY; CLASS_COUNT]),
+        }
+    }
+
+    /// Returns the index of the size class that serves the given layout, or None if it is a large allocation
+    const fn class_index(layout: Layout) -> Option<usize> {
+        let size = if layout.size() > layout.align() {
+            layout.size()
+        } else {
+            layout.align()
+        };
+
+        if size > MAX_SMALL_SIZE || layout.align() > MAX_SMALL_ALIGN {
+            return None;
+        }
+
+        let shift = size.next_power_of_two().trailing_zeros();
+        Some(shift.saturating_sub(MIN_CLASS_SHIFT) as usize)
+    }
+
+    const fn class_size(index: usize) -> usize {
+        MIN_CLASS_SIZE << index
+    }
+
+    /// Maps a new slab for the size class `index`, returns the slab's start and end addresses
+    fn map_slab(index: usize) -> Option<(usize, usize)> {
+        let page_size = B::page_size();
+        let slab_size = MIN_SLAB_SIZE.max(Self::class_size(index) * MIN_SLAB_BLOCKS);
+        let page_count = slab_size.div_ceil(page_size);
+
+        let config = RawMemMapConfig::new(None, page_count, 0, None);
+        let (_, start) = B::mem_map(&config, MAP_FLAGS).ok()?;
+
+        let start = start.as_ptr() as usize;
+        Some((start, start + page_count * page_size))
+    }
+
+    unsafe fn alloc_small(&self, index: usize) -> *mut u8 {
+        self.lock.lock();
+        let class = unsafe { &mut (*self.classes.get())[index] };
+        let size = Self::class_size(index);
+
+        let results = if !class.free_list.is_null() {
+            let block = class.free_list;
+            class.free_list = unsafe { (*block).next };
+            block.cast()
+        } else {
+            if class.cursor + size > class.slab_end {
+                // the current slab is exhausted
+                match Self::map_slab(index) {
+                    Some((start, end)) => {
+                        class.cursor = start;
+                        class.slab_end = end;
+                    }
+                    None => {
+                        self.lock.unlock();
+                        return ptr::null_mut();
+                    }
+                }
+            }
+
+            let block = class.cursor;
+            class.cursor += size;
+            block as *mut u8
+        };
+
+        self.lock.unlock();
+        results
+    }
+
+    unsafe fn dealloc_small(&self, ptr: *mut u8, index: usize) {
+        self.lock.lock();
+        let class = unsafe { &mut (*self.classes.get())[index] };
+
+        let block = ptr.cast::<FreeBlock>();
+        unsafe {
+            block.write(FreeBlock {
+                next: class.free_list,
+            })
+        };
+        class.free_list = block;
+
+        self.lock.unlock();
+    }
+
+    /// Maps a large allocation, the first page (or more to satisfy the alignment) holds the [`LargeHeader`] right before the returned pointer
+    unsafe fn alloc_large(&self, layout: Layout) -> *mut u8 {
+        let page_size = B::page_size();
+        let align_pages = layout.align().max(page_size) / page_size;
+
+        let Some(page_count) = layout.size().div_ceil(page_size).checked_add(align_pages) else {
+            return ptr::null_mut();
+        };
+
+        let config = RawMemMapConfig::new(None, page_count, LARGE_GUARD_PAGES, None);
+        let Ok((resource, start)) = B::mem_map(&config, MAP_FLAGS) else {
+            return ptr::null_mut();
+        };
+
+        let start = start.as_ptr() as usize;
+        let ptr = (start + page_size).next_multiple_of(layout.align().max(page_size));
+
+        unsafe {
+            let header = (ptr as *mut LargeHeader).sub(1);
+            header.write(LargeHeader {
+                resource,
+                page_count,
+                head: ptr - start,
+            });
+        }
+
+        ptr as *mut u8
+    }
+
+    /// Attempts to resize a large allocation without copying it, returns None if it has to be moved to a new mapping
+    unsafe fn realloc_large(
+        &self,
+        ptr: *mut u8,
+        layout: Layout,
+        new_size: usize,
+    ) -> Option<*mut u8> {
+        let page_size = B::page_size();
+        let header = unsafe { (ptr as *const LargeHeader).sub(1).read() };
+        let capacity = header.page_count * page_size - header.head;
+        let page_count = header.head.checked_add(new_size)?.div_ceil(page_size);
+
+        if page_count == header.page_count {
+            return Some(ptr);
+        }
+
+        // moving the mapping only keeps the returned pointer aligned to the page size
+        let flags = if layout.align() <= page_size {
+            MemRemapFlags::MAY_MOVE
+        } else {
+            MemRemapFlags::NONE
+        };
+
+        match B::mem_remap(header.resource, page_count, flags) {
+            Ok(start) => {
+                let ptr = start.as_ptr() as usize + header.head;
+                unsafe {
+                    let header_ptr = (ptr as *mut LargeHeader).sub(1);
+                    (*header_ptr).page_count = page_count;
+                }
+                Some(ptr as *mut u8)
+            }
+            // shrinking, or growing within the pages the mapping already has
+            Err(_) if new_size <= capacity => Some(ptr),
+            Err(_) => None,
+        }
+    }
+
+    unsafe fn dealloc_large(&self, ptr: *mut u8) {
+        let header = unsafe { (ptr as *const LargeHeader).sub(1).read() };
+        B::destroy(header.resource);
+    }
+}
+
+impl<B: MemBackend> Default for SafaAlloc<B> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+unsafe impl<B: MemBackend> GlobalAlloc for SafaAlloc<B> {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        match Self::class_index(layout) {
+            Some(index) => unsafe { self.alloc_small(index) },
+            None => unsafe { self.alloc_large(layout) },
+        }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        match Self::class_index(layout) {
+            Some(index) => unsafe { self.dealloc_small(ptr, index) },
+            None => unsafe { self.dealloc_large(ptr) },
+        }
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_layout = unsafe { Layout::from_size_align_unchecked(new_size, layout.align()) };
+
+        let old_class = Self::class_index(layout);
+        let new_class = Self::class_index(new_layout);
+        if old_class.is_some() && old_class == new_class {
+            // the block is already big enough
+            return ptr;
+        }
+
+        if old_class.is_none()
+            && new_class.is_none()
+            && let Some(new_ptr) = unsafe { self.realloc_large(ptr, layout, new_size) }
+        {
+            return new_ptr;
+        }
+
+        let new_ptr = unsafe { self.alloc(new_layout) };
+        if !new_ptr.is_null() {
+            unsafe {
+                ptr::copy_nonoverlapping(ptr, new_ptr, layout.size().min(new_size));
+                self.dealloc(ptr, layout);
+            }
+        }
+        new_ptr
+    }
+}
@@ -74,7 +74,8 @@ pub enum ErrorStatus {
     AddressNotFound = 0x26,
     /// A given input buffer size is not acceptable by the attempted operation
     InvalidSize = 0x27,
-    /// The syscall was interrupted by something, such as a kill command.
+    /// The syscall was interrupted by something, such as a signal handler,
+    /// also the default reason code given to [`crate::syscalls::SyscallTable::SysPKill`] and [`crate::syscalls::SyscallTable::SysTKill`]
     ForceTerminated = 0x28,
     /// Attempt to use an address thats already used.
     AddressAlreadyInUse = 0x29,
@@ -85,11 +86,19 @@ pub enum ErrorStatus {
     /// Network is unreachable
     NetworkUnreachable = 0x2C,
     ProtocolNotSupported = 0x2D,
+    /// The requested extended attribute doesn't exist
+    NoSuchAttribute = 0x2E,
+    /// An extended attribute value exceeds [`crate::consts::MAX_XATTR_VALUE_LENGTH`] or the space available for attributes
+    AttributeTooLarge = 0x2F,
+    /// Attempt to link two paths that are on different drives
+    CrossDriveLink = 0x30,
+    /// The drive has no space left, unlike [`ErrorStatus::OutOfMemory`] which is about memory
+    NoSpaceLeft = 0x31,
 }
 
 impl ErrorStatus {
     // update when a new error is added
-    const MAX: u16 = Self::ProtocolNotSupported as u16;
+    const MAX: u16 = Self::NoSpaceLeft as u16;
 
     #[inline(always)]
     /// Gives a string description of the error
@@ -141,6 +150,10 @@ impl ErrorStatus {
             HostUnreachable => "Host Unreachable",
             NetworkUnreachable => "Network Unreachable",
             ProtocolNotSupported => "Protocol Not Supported",
+            NoSuchAttribute => "No Such Attribute",
+            AttributeTooLarge => "Attribute Too Large",
+            CrossDriveLink => "Cross Drive Link",
+            NoSpaceLeft => "No Space Left on Drive",
         }
     }
 
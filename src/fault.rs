@@ -0,0 +1,143 @@
+//! Hardware fault and exception delivery ABI structures
+use core::ops::BitOr;
+
+use crate::mem::MemFlags;
+
+/// The kind of a hardware fault or exception raised by a userspace thread
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u32)]
+pub enum FaultKind {
+    /// Access to unmapped memory, including guard pages and null pointers
+    PageFault = 0,
+    /// Access to mapped memory that violates its protection [`MemFlags`], for example writing to read-only memory
+    ProtectionFault = 1,
+    IllegalInstruction = 2,
+    /// Integer division by zero or overflow
+    DivideError = 3,
+    /// A breakpoint instruction was executed
+    Breakpoint = 4,
+    /// A misaligned memory access on an architecture or instruction that requires alignment
+    Misalignment = 5,
+}
+
+impl FaultKind {
+    pub const fn try_from(value: u32) -> Option<Self> {
+        match value {
+            0 => Some(Self::PageFault),
+            1 => Some(Self::ProtectionFault),
+            2 => Some(Self::IllegalInstruction),
+            3 => Some(Self::DivideError),
+            4 => Some(Self::Breakpoint),
+            5 => Some(Self::Misalignment),
+            _ => None,
+        }
+    }
+}
+
+/// A set of [`FaultKind`]s
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct FaultKinds(u32);
+
+impl FaultKinds {
+    pub const NONE: Self = Self(0);
+    pub const ALL: Self = Self((1 << 6) - 1);
+
+    /// Returns a set that only contains the given kind
+    pub const fn of(kind: FaultKind) -> Self {
+        Self(1 << kind as u32)
+    }
+
+    pub const fn from_bits(bits: u32) -> Self {
+        Self(bits)
+    }
+
+    pub const fn to_bits(self) -> u32 {
+        self.0
+    }
+
+    pub const fn contains(self, other: Self) -> bool {
+        (self.0 & other.0) == other.0
+    }
+
+    pub const fn contains_kind(self, kind: FaultKind) -> bool {
+        self.contains(Self::of(kind))
+    }
+}
+
+impl BitOr for FaultKinds {
+    type Output = Self;
+    fn bitor(self, rhs: Self) -> Self::Output {
+        Self(self.0 | rhs.0)
+    }
+}
+
+/// Describes a fault raised by a userspace thread, passed to fault handlers registered with [`crate::syscalls::SyscallTable::SysPSetFaultHandler`]
+/// and read from fault resources opened with [`crate::syscalls::SyscallTable::SysPFaultOpen`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(C)]
+pub struct FaultRecord {
+    pub kind: FaultKind,
+    /// The kind of the memory access that caused a [`FaultKind::PageFault`], [`FaultKind::ProtectionFault`] or [`FaultKind::Misalignment`],
+    /// contains one of [`MemFlags::READ`], [`MemFlags::WRITE`] or [`MemFlags::EXEC`], [`MemFlags::NONE`] for other kinds
+    pub access: MemFlags,
+    _reserved: [u8; 3],
+    /// The ID of the faulting thread
+    pub tid: u32,
+    _reserved1: u32,
+    /// The address that was accessed, 0 for kinds that don't access memory
+    pub fault_addr: usize,
+    /// The address of the faulting instruction
+    pub ip: usize,
+}
+
+impl FaultRecord {
+    pub const fn new(
+        kind: FaultKind,
+        access: MemFlags,
+        tid: u32,
+        fault_addr: usize,
+        ip: usize,
+    ) -> Self {
+        Self {
+            kind,
+            access,
+            _reserved: [0; 3],
+            tid,
+            _reserved1: 0,
+            fault_addr,
+            ip,
+        }
+    }
+}
+
+/// A userspace fault handler, runs on the faulting thread
+///
+/// Returns the address of the instruction to resume the thread at, which can be [`FaultRecord::ip`] to retry the faulting instruction,
+/// or 0 to terminate the process as if no handler was registered
+pub type FaultHandler = extern "C" fn(record: &FaultRecord) -> usize;
+
+/// configuration for the [`crate::syscalls::SyscallTable::SysPSetFaultHandler`] syscall
+#[repr(C)]
+pub struct RawFaultHandlerConfig {
+    /// config version for compatibility
+    pub revision: u32,
+    /// The kinds of faults to handle, the handler isn't invoked for other kinds
+    pub kinds: FaultKinds,
+    /// The handler, None to remove the registered handler
+    pub handler: Option<FaultHandler>,
+}
+
+impl RawFaultHandlerConfig {
+    /// The revision of configurations created by this version of the ABI
+    pub const LATEST_REVISION: u32 = 0;
+
+    /// Creates a new fault handler configuration with the latest revision
+    pub const fn new(kinds: FaultKinds, handler: Option<FaultHandler>) -> Self {
+        Self {
+            revision: Self::LATEST_REVISION,
+            kinds,
+            handler,
+        }
+    }
+}
@@ -0,0 +1,144 @@
+use crate::ffi::{
+    NotZeroable,
+    slice::{InvalidSliceError, Slice},
+};
+
+/// An FFI safe buffer to write from, used by vectored I/O such as [`crate::syscalls::SyscallTable::SysIOWriteV`]
+///
+/// Has the same layout as [`Slice<u8>`] and [`IoSliceMut`], so a [`Slice`] of [`IoSliceMut`]s can be reinterpreted as a [`Slice`] of [`IoSlice`]s.
+#[derive(Debug, Clone, Copy)]
+#[repr(transparent)]
+pub struct IoSlice(Slice<u8>);
+
+impl IoSlice {
+    /// Creates a new [`IoSlice`] from a byte slice.
+    pub const fn new(buf: &[u8]) -> Self {
+        Self(Slice::from_slice(buf))
+    }
+
+    /// Creates a new [`IoSlice`] from a raw FFI [`Slice`].
+    pub const fn from_raw(raw: Slice<u8>) -> Self {
+        Self(raw)
+    }
+
+    pub const fn as_raw(&self) -> &Slice<u8> {
+        &self.0
+    }
+
+    pub const fn as_ptr(&self) -> *const u8 {
+        self.0.as_ptr()
+    }
+
+    pub const fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub const fn is_empty(&self) -> bool {
+        self.0.len() == 0
+    }
+
+    /// Attempts to convert [`IoSlice`] into a byte slice, running the pointer into a custom validator function
+    ///
+    /// # Safety
+    /// The buffer must be valid for the returned lifetime `'a`, the validator is responsible for checking that if the buffer was passed from a foreign callsite.
+    #[inline]
+    pub unsafe fn try_as_slice_custom<'a>(
+        &self,
+        validator: impl Fn(*const ()) -> bool,
+    ) -> Result<&'a [u8], InvalidSliceError> {
+        unsafe { self.0.try_as_slice_custom(validator) }
+    }
+
+    /// Attempts to convert [`IoSlice`] into a byte slice.
+    ///
+    /// # Safety
+    /// The buffer must be valid for the returned lifetime `'a`.
+    #[inline]
+    pub unsafe fn try_as_slice<'a>(&self) -> Result<&'a [u8], InvalidSliceError> {
+        unsafe { self.0.try_as_slice() }
+    }
+}
+
+/// An FFI safe buffer to read into, used by vectored I/O such as [`crate::syscalls::SyscallTable::SysIOReadV`]
+///
+/// Has the same layout as [`Slice<u8>`] and [`IoSlice`].
+#[derive(Debug)]
+#[repr(transparent)]
+pub struct IoSliceMut(Slice<u8>);
+
+impl IoSliceMut {
+    /// Creates a new [`IoSliceMut`] from a mutable byte slice.
+    pub const fn new(buf: &mut [u8]) -> Self {
+        Self(Slice::from_slice_mut(buf))
+    }
+
+    /// Creates a new [`IoSliceMut`] from a raw FFI [`Slice`].
+    pub const fn from_raw(raw: Slice<u8>) -> Self {
+        Self(raw)
+    }
+
+    pub const fn as_raw(&self) -> &Slice<u8> {
+        &self.0
+    }
+
+    pub const fn as_io_slice(&self) -> IoSlice {
+        IoSlice(self.0)
+    }
+
+    pub const fn as_ptr(&self) -> *const u8 {
+        self.0.as_ptr()
+    }
+
+    pub const fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub const fn is_empty(&self) -> bool {
+        self.0.len() == 0
+    }
+
+    /// Attempts to convert [`IoSliceMut`] into a mutable byte slice, running the pointer into a custom validator function
+    ///
+    /// # Safety
+    /// The buffer must be valid for the returned lifetime `'a`, the validator is responsible for checking that if the buffer was passed from a foreign callsite.
+    #[inline]
+    pub unsafe fn try_as_slice_mut_custom<'a>(
+        &self,
+        validator: impl FnOnce(*const ()) -> bool,
+    ) -> Result<&'a mut [u8], InvalidSliceError> {
+        unsafe { self.0.try_as_slice_mut_custom(validator) }
+    }
+
+    /// Attempts to convert [`IoSliceMut`] into a mutable byte slice.
+    ///
+    /// # Safety
+    /// The buffer must be valid for the returned lifetime `'a` and not aliased.
+    #[inline]
+    pub unsafe fn try_as_slice_mut<'a>(&self) -> Result<&'a mut [u8], InvalidSliceError> {
+        unsafe { self.0.try_as_slice_mut() }
+    }
+}
+
+impl Slice<IoSliceMut> {
+    /// Reinterprets a [`Slice`] of [`IoSliceMut`]s as a [`Slice`] of [`IoSlice`]s, this is always valid because they share the same layout.
+    pub const fn as_io_slices(&self) -> Slice<IoSlice> {
+        Slice {
+            ptr: self.ptr.cast(),
+            len: self.len,
+        }
+    }
+}
+
+impl NotZeroable for IoSlice {
+    #[inline(always)]
+    fn is_zero(&self) -> bool {
+        self.0.is_zero()
+    }
+}
+
+impl NotZeroable for IoSliceMut {
+    #[inline(always)]
+    fn is_zero(&self) -> bool {
+        self.0.is_zero()
+    }
+}
@@ -2,12 +2,15 @@
 //!
 //! for example exports [`RawSlice<T>`] which is an FFI safe alternative to `&[T]`
 
+pub mod io;
 pub mod num;
 pub mod option;
 pub mod ptr;
 pub mod slice;
 pub mod str;
 
+pub use io::{IoSlice, IoSliceMut};
+
 /// Defines a trait for types that are invalid when passed as a zero
 pub trait NotZeroable: Sized {
     fn is_zero(&self) -> bool;
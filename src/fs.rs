@@ -3,6 +3,10 @@ use core::ops::BitOr;
 
 use crate::consts;
 
+/// A sentinel directory resource ID, passed to the directory-relative (`*At`) filesystem syscalls such as [`crate::syscalls::SyscallTable::SysFSOpenAt`],
+/// to resolve the path relative to the current working directory instead of a directory resource
+pub const CWD_RESOURCE: u32 = u32::MAX;
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 #[repr(u8)]
 pub enum FSObjectType {
@@ -11,6 +15,108 @@ pub enum FSObjectType {
     Device,
 }
 
+/// A user ID that owns filesystem objects and processes, see [`crate::process::ProcessCredentials`]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct Uid(u32);
+
+impl Uid {
+    /// The superuser, processes whose [`crate::process::ProcessCredentials::uid`] is root bypass all [`FileMode`] checks and are allowed privileged syscalls
+    pub const ROOT: Self = Self(0);
+    /// Passed to [`crate::syscalls::SyscallTable::SysFSChown`] to leave the owner unchanged
+    pub const UNCHANGED: Self = Self(u32::MAX);
+
+    pub const fn new(id: u32) -> Self {
+        Self(id)
+    }
+
+    pub const fn get(self) -> u32 {
+        self.0
+    }
+}
+
+/// A group ID that owns filesystem objects and processes, see [`crate::process::ProcessCredentials`]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct Gid(u32);
+
+impl Gid {
+    /// The superuser's group, unlike [`Uid::ROOT`] it doesn't grant any privileges beyond the group bits of a [`FileMode`]
+    pub const ROOT: Self = Self(0);
+    /// Passed to [`crate::syscalls::SyscallTable::SysFSChown`] to leave the group unchanged
+    pub const UNCHANGED: Self = Self(u32::MAX);
+
+    pub const fn new(id: u32) -> Self {
+        Self(id)
+    }
+
+    pub const fn get(self) -> u32 {
+        self.0
+    }
+}
+
+/// The permission bits of a filesystem object, uses the same bit values as the traditional unix octal modes
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub struct FileMode(u16);
+
+impl BitOr for FileMode {
+    type Output = Self;
+
+    fn bitor(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+}
+
+impl FileMode {
+    pub const NONE: Self = Self(0);
+
+    pub const OWNER_READ: Self = Self(0o400);
+    pub const OWNER_WRITE: Self = Self(0o200);
+    pub const OWNER_EXEC: Self = Self(0o100);
+    pub const GROUP_READ: Self = Self(0o040);
+    pub const GROUP_WRITE: Self = Self(0o020);
+    pub const GROUP_EXEC: Self = Self(0o010);
+    pub const OTHER_READ: Self = Self(0o004);
+    pub const OTHER_WRITE: Self = Self(0o002);
+    pub const OTHER_EXEC: Self = Self(0o001);
+
+    /// Spawning a process from the file runs it as the file's owner, see [`crate::process::ProcessCredentials`]
+    pub const SETUID: Self = Self(0o4000);
+    /// Spawning a process from the file runs it as the file's group, see [`crate::process::ProcessCredentials`]
+    pub const SETGID: Self = Self(0o2000);
+    /// For directories, only the owner of an entry (or of the directory) can remove or rename it
+    pub const STICKY: Self = Self(0o1000);
+
+    /// The mode given to newly created files, rw-r--r--
+    pub const DEFAULT_FILE: Self = Self(0o644);
+    /// The mode given to newly created directories, rwxr-xr-x
+    pub const DEFAULT_DIRECTORY: Self = Self(0o755);
+
+    /// All the bits that have a meaning, other bits are rejected with [`crate::errors::ErrorStatus::InvalidArgument`]
+    pub const ALL: Self = Self(0o7777);
+
+    pub const fn from_bits(bits: u16) -> Self {
+        Self(bits)
+    }
+
+    pub const fn to_bits(self) -> u16 {
+        self.0
+    }
+
+    pub const fn contains(self, other: Self) -> bool {
+        (self.0 & other.0) == other.0
+    }
+
+    /// Returns true if the mode doesn't contain any undefined bits
+    pub const fn is_valid(self) -> bool {
+        self.0 & !Self::ALL.0 == 0
+    }
+}
+
+/// The unit of [`FileAttr::allocated_blocks`] in bytes, independent of the filesystem's actual block size
+pub const ALLOCATED_BLOCK_SIZE: usize = 512;
+
 // Keep in sync with kernel implementition in kernel::vfs::expose::FileAttr
 // The ABI version cannot be used directly in the kernel implementition
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -18,11 +124,133 @@ pub enum FSObjectType {
 pub struct FileAttr {
     pub kind: FSObjectType,
     pub size: usize,
+    pub mode: FileMode,
+    pub uid: Uid,
+    pub gid: Gid,
+    /// The number of names (directory entries) referring to the object, see [`crate::syscalls::SyscallTable::SysFSLink`]
+    pub link_count: u32,
+    /// The amount of storage actually allocated for the object in units of [`ALLOCATED_BLOCK_SIZE`] bytes,
+    /// a file using less than `size` bytes is sparse (has holes)
+    pub allocated_blocks: usize,
 }
 
 impl FileAttr {
+    /// Creates a new [`FileAttr`] owned by root with the default [`FileMode`] for the given kind
     pub const fn new(kind: FSObjectType, size: usize) -> Self {
-        Self { kind, size }
+        let mode = match kind {
+            FSObjectType::Directory => FileMode::DEFAULT_DIRECTORY,
+            FSObjectType::File | FSObjectType::Device => FileMode::DEFAULT_FILE,
+        };
+
+        Self {
+            kind,
+            size,
+            mode,
+            uid: Uid::ROOT,
+            gid: Gid::ROOT,
+            link_count: 1,
+            allocated_blocks: size.div_ceil(ALLOCATED_BLOCK_SIZE),
+        }
+    }
+
+    /// Sets the permission bits and the ownership of this [`FileAttr`]
+    pub const fn with_permissions(self, mode: FileMode, uid: Uid, gid: Gid) -> Self {
+        Self {
+            mode,
+            uid,
+            gid,
+            ..self
+        }
+    }
+
+    /// Sets the number of names referring to the object of this [`FileAttr`]
+    pub const fn with_link_count(self, link_count: u32) -> Self {
+        Self { link_count, ..self }
+    }
+
+    /// Sets the amount of allocated blocks of this [`FileAttr`], by default it is assumed that the object isn't sparse
+    pub const fn with_allocated_blocks(self, allocated_blocks: usize) -> Self {
+        Self {
+            allocated_blocks,
+            ..self
+        }
+    }
+}
+
+/// The class of a device, describes what a [`FSObjectType::Device`] is without guessing from its path
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u32)]
+pub enum DeviceClass {
+    /// A device that doesn't fit into any other class
+    Other = 0,
+    /// A character stream device, such as a serial port
+    CharStream = 1,
+    /// A block storage device
+    Block = 2,
+    Framebuffer = 3,
+    /// A keyboard, reads produce [`crate::input::KeyEvent`]s
+    Keyboard = 4,
+    /// A mouse, reads produce [`crate::input::MiceEvent`]s
+    Mouse = 5,
+    /// A VTTY interface allocated with [`crate::syscalls::SyscallTable::SysVTTYAlloc`]
+    VTTY = 6,
+    NetworkInterface = 7,
+    /// A random number generator
+    RNG = 8,
+    Audio = 9,
+}
+
+impl DeviceClass {
+    pub const fn try_from(value: u32) -> Option<Self> {
+        match value {
+            0 => Some(Self::Other),
+            1 => Some(Self::CharStream),
+            2 => Some(Self::Block),
+            3 => Some(Self::Framebuffer),
+            4 => Some(Self::Keyboard),
+            5 => Some(Self::Mouse),
+            6 => Some(Self::VTTY),
+            7 => Some(Self::NetworkInterface),
+            8 => Some(Self::RNG),
+            9 => Some(Self::Audio),
+            _ => None,
+        }
+    }
+}
+
+/// Information about a device resource, returned by [`crate::syscalls::SyscallTable::SysFDeviceInfo`]
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[repr(C)]
+pub struct DeviceInfo {
+    pub class: DeviceClass,
+    /// Identifies the driver handling the device, unique for each driver
+    pub major: u32,
+    /// Identifies the device instance among the devices handled by the same driver
+    pub minor: u32,
+    _reserved: u32,
+    pub driver_name_length: usize,
+    pub driver_name: [u8; consts::MAX_NAME_LENGTH],
+}
+
+impl DeviceInfo {
+    pub fn new(class: DeviceClass, driver_name: &str, major: u32, minor: u32) -> Self {
+        let driver_name_length = driver_name.len().min(consts::MAX_NAME_LENGTH);
+        let mut name_bytes = [0u8; consts::MAX_NAME_LENGTH];
+        name_bytes[..driver_name_length]
+            .copy_from_slice(&driver_name.as_bytes()[..driver_name_length]);
+        Self {
+            class,
+            major,
+            minor,
+            _reserved: 0,
+            driver_name_length,
+            driver_name: name_bytes,
+        }
+    }
+
+    /// Returns the bytes of the driver name, should be valid UTF-8
+    pub fn driver_name(&self) -> &[u8] {
+        &self.driver_name[..self.driver_name_length.min(consts::MAX_NAME_LENGTH)]
     }
 }
 
@@ -50,6 +278,8 @@ impl DirEntry {
 }
 
 /// Describes the options for opening a file or directory.
+///
+/// [`Self::READ`] and [`Self::WRITE`] are checked against the object's [`FileMode`], see [`crate::syscalls::SyscallTable::SysFSOpen`].
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
 #[repr(transparent)]
 pub struct OpenOptions(u8);
@@ -69,10 +299,14 @@ impl OpenOptions {
     pub const READ: Self = Self(1 << 1);
     /// Create the file if it does not exist.
     pub const CREATE_FILE: Self = Self(1 << 2);
-    /// Create the directory if it does not exist. (doesn't create parent directories)
+    /// Create the directory if it does not exist. (doesn't create parent directories unless [`Self::CREATE_PARENTS`] is given)
     pub const CREATE_DIRECTORY: Self = Self(1 << 3);
     /// Truncate the file to zero length if it already exists.
     pub const WRITE_TRUNCATE: Self = Self(1 << 4);
+    /// Combined with [`Self::CREATE_FILE`] or [`Self::CREATE_DIRECTORY`], create any missing parent directories first, like `mkdir -p`.
+    ///
+    /// Errors with [`crate::errors::ErrorStatus::NotADirectory`] if the path's parent or any of its parents exists and isn't a directory.
+    pub const CREATE_PARENTS: Self = Self(1 << 5);
     // no append because the user would provide the offset anyways
 
     pub const fn from_bits(bits: u8) -> Self {
@@ -102,4 +336,180 @@ impl OpenOptions {
     pub const fn create_dir(&self) -> bool {
         self.contains(Self::CREATE_DIRECTORY)
     }
+
+    pub const fn create_parents(&self) -> bool {
+        self.contains(Self::CREATE_PARENTS)
+    }
+}
+
+/// Describes the operation performed by [`crate::syscalls::SyscallTable::SysFLock`].
+///
+/// Exactly one of [`Self::SHARED`], [`Self::EXCLUSIVE`] or [`Self::UNLOCK`] must be given,
+/// otherwise the syscall fails with [`crate::errors::ErrorStatus::InvalidArgument`].
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[repr(transparent)]
+pub struct FileLockFlags(u8);
+
+impl BitOr for FileLockFlags {
+    type Output = Self;
+
+    fn bitor(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+}
+
+impl FileLockFlags {
+    pub const NONE: Self = Self(0);
+    /// Acquire a shared (read) lock, multiple shared locks may overlap.
+    pub const SHARED: Self = Self(1 << 0);
+    /// Acquire an exclusive (write) lock, doesn't overlap with any other lock.
+    pub const EXCLUSIVE: Self = Self(1 << 1);
+    /// Release a previously acquired lock over the given range.
+    pub const UNLOCK: Self = Self(1 << 2);
+    /// Don't block if the lock is held by someone else, return [`crate::errors::ErrorStatus::WouldBlock`] instead.
+    pub const NON_BLOCKING: Self = Self(1 << 3);
+
+    pub const fn from_bits(bits: u8) -> Self {
+        Self(bits)
+    }
+
+    pub const fn to_bits(self) -> u8 {
+        self.0
+    }
+
+    pub const fn contains(self, other: Self) -> bool {
+        (self.0 & other.0) == other.0
+    }
+
+    pub const fn is_blocking(&self) -> bool {
+        !self.contains(Self::NON_BLOCKING)
+    }
+}
+
+/// A byte range of a file to lock, passed to [`crate::syscalls::SyscallTable::SysFLock`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(C)]
+pub struct FileLockRange {
+    /// The offset of the first byte in the range, the same as the offsets of [`crate::syscalls::SyscallTable::SysIORead`] and [`crate::syscalls::SyscallTable::SysIOWrite`],
+    /// a negative offset errors with [`crate::errors::ErrorStatus::InvalidOffset`]
+    pub offset: isize,
+    /// The length of the range in bytes, zero means the range extends to the end of the file,
+    /// including bytes appended after the lock was acquired
+    pub length: usize,
+}
+
+impl FileLockRange {
+    /// A range that covers the whole file
+    pub const WHOLE_FILE: Self = Self::new(0, 0);
+
+    pub const fn new(offset: isize, length: usize) -> Self {
+        Self { offset, length }
+    }
+
+    pub const fn is_whole_file(&self) -> bool {
+        self.offset == 0 && self.length == 0
+    }
+}
+
+/// Flags passed to [`crate::syscalls::SyscallTable::SysFSSetXAttr`], by default the attribute is created or replaced
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[repr(transparent)]
+pub struct XAttrFlags(u8);
+
+impl BitOr for XAttrFlags {
+    type Output = Self;
+
+    fn bitor(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+}
+
+impl XAttrFlags {
+    pub const NONE: Self = Self(0);
+    /// Fail with [`crate::errors::ErrorStatus::AlreadyExists`] if the attribute already exists.
+    pub const CREATE: Self = Self(1 << 0);
+    /// Fail with [`crate::errors::ErrorStatus::NoSuchAttribute`] if the attribute doesn't exist.
+    pub const REPLACE: Self = Self(1 << 1);
+
+    pub const fn from_bits(bits: u8) -> Self {
+        Self(bits)
+    }
+
+    pub const fn to_bits(self) -> u8 {
+        self.0
+    }
+
+    pub const fn contains(self, other: Self) -> bool {
+        (self.0 & other.0) == other.0
+    }
+}
+
+/// Describes the operation performed by [`crate::syscalls::SyscallTable::SysIOAllocate`],
+/// by default (no flags) the range is preallocated and the file is extended if the range ends past the end of the file
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[repr(transparent)]
+pub struct FileAllocateMode(u8);
+
+impl BitOr for FileAllocateMode {
+    type Output = Self;
+
+    fn bitor(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+}
+
+impl FileAllocateMode {
+    /// No flags, preallocates the range
+    pub const NONE: Self = Self(0);
+    /// Don't change the file size even if the range ends past the end of the file, the allocated space past the end is kept for future writes
+    pub const KEEP_SIZE: Self = Self(1 << 0);
+    /// Deallocate the range turning it into a hole that reads as zeros, never changes the file size
+    pub const PUNCH_HOLE: Self = Self(1 << 1);
+    /// Zero the range and make sure it is allocated, extends the file unless [`Self::KEEP_SIZE`] is given
+    pub const ZERO_RANGE: Self = Self(1 << 2);
+
+    pub const fn from_bits(bits: u8) -> Self {
+        Self(bits)
+    }
+
+    pub const fn to_bits(self) -> u8 {
+        self.0
+    }
+
+    pub const fn contains(self, other: Self) -> bool {
+        (self.0 & other.0) == other.0
+    }
+}
+
+/// Flags passed to [`crate::syscalls::SyscallTable::SysIOSyncRange`], by default both the data and the metadata are synced and the syscall waits for the writeback to complete
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[repr(transparent)]
+pub struct SyncFlags(u8);
+
+impl BitOr for SyncFlags {
+    type Output = Self;
+
+    fn bitor(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+}
+
+impl SyncFlags {
+    pub const NONE: Self = Self(0);
+    /// Only sync the data and the metadata required to read it back (such as the file size), skipping metadata such as timestamps
+    pub const DATA_ONLY: Self = Self(1 << 0);
+    /// Only initiate the writeback and return without waiting for it to complete
+    pub const ASYNC: Self = Self(1 << 1);
+
+    pub const fn from_bits(bits: u8) -> Self {
+        Self(bits)
+    }
+
+    pub const fn to_bits(self) -> u8 {
+        self.0
+    }
+
+    pub const fn contains(self, other: Self) -> bool {
+        (self.0 & other.0) == other.0
+    }
 }
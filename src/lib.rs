@@ -3,9 +3,12 @@
 #[cfg(not(any(target_os = "safaos", target_os = "none")))]
 compile_error!("abi should only be used for SafaOS or freestanding targets");
 
+#[cfg(feature = "alloc")]
+pub mod allocator;
 pub mod arch;
 pub mod clock;
 pub mod errors;
+pub mod fault;
 pub mod ffi;
 pub mod fs;
 pub mod input;
@@ -13,6 +16,7 @@ pub mod mem;
 pub mod net;
 pub mod poll;
 pub mod process;
+pub mod signal;
 pub mod sockets;
 pub mod syscalls;
 
@@ -23,4 +27,6 @@ pub mod consts {
     pub const MAX_PATH_LENGTH: usize = 1024;
     /// defines the max byte length for drive names (not counted in [`MAX_PATH_LENGTH`])
     pub const MAX_DRIVE_NAME_LENGTH: usize = 8;
+    /// defines the max byte length for extended attribute values
+    pub const MAX_XATTR_VALUE_LENGTH: usize = 64 * 1024;
 }
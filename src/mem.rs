@@ -1,24 +1,242 @@
 use core::ops::{BitAnd, BitOr};
 
+use crate::consts;
+use crate::errors::{ErrorStatus, IntoErr};
+
 /// Passed to [`crate::syscalls::SyscallTable::SysMemMap`], to describe the address, the size, of the area to map to, and the resource it is associated with
+///
+/// The kernel should check the configuration with [`RawMemMapConfig::validate`] before using it.
+#[derive(Debug, Clone, Copy)]
 #[repr(C)]
 pub struct RawMemMapConfig {
+    /// config version for compatibility, new fields are only added at the end and only read if the revision is high enough,
+    /// so that new mapping features don't need a new syscall
+    /// revision 0: added the revision header
+    pub revision: u32,
+    _reserved: u32,
     /// Address hint can be null to indicate no hint
+    ///
+    /// The hint is the start of the area itself (the same as [`MemMapInfo::start`]), the leading guard pages are placed right below it
     pub addr_hint: *const (),
     /// The amount of pages to map
+    ///
+    /// Pages are of the size selected by the flags, [`PageSizes::base`] by default,
+    /// or [`PageSizes::large`] and [`PageSizes::huge`] with [`MemMapFlags::LARGE_PAGES`] and [`MemMapFlags::HUGE_PAGES`],
+    /// the address hint must then be aligned to the selected page size
     pub page_count: usize,
-    /// The amount of unmapped guard pages before and after the area
+    /// The amount of unmapped guard pages before and after the area, in the same page size as [`Self::page_count`]
     pub guard_pages_count: usize,
     /// The ID of the associated Resource to map, ignored unless the flag [`MemMapFlags::MAP_RESOURCE`] was given
     pub resource_to_map: usize,
-    /// If Mapping A Resource, Describes the offset to where the memory mapping
+    /// If Mapping A Resource, Describes the offset to where the memory mapping,
+    /// must be aligned to the selected page size
     pub resource_off: isize,
 }
 
+/// An error returned by [`RawMemMapConfig::validate`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MemMapConfigError {
+    /// The revision is newer than [`RawMemMapConfig::LATEST_REVISION`]
+    UnknownRevision,
+    /// The page size selected by the flags isn't supported, or both [`MemMapFlags::LARGE_PAGES`] and [`MemMapFlags::HUGE_PAGES`] were given
+    UnsupportedPageSize,
+    /// The address hint isn't aligned to the selected page size
+    MisalignedHint,
+    /// `page_count` is zero
+    ZeroPageCount,
+    /// The size in bytes of `page_count` plus the guard pages before and after the area overflows
+    PageCountOverflow,
+    /// Given the address hint, the leading guard pages start below address zero or the area and the trailing guard pages end past the address space
+    HintOutOfRange,
+    /// The resource offset is negative
+    NegativeOffset,
+    /// The resource offset isn't aligned to the selected page size
+    MisalignedOffset,
+    /// The resource to map doesn't fit in a Resource ID (is larger than [`u32::MAX`])
+    InvalidResource,
+    /// A reserved field isn't zero
+    NonZeroReserved,
+}
+
+impl IntoErr for MemMapConfigError {
+    fn into_err(self) -> ErrorStatus {
+        match self {
+            Self::UnknownRevision | Self::UnsupportedPageSize => ErrorStatus::NotSupported,
+            Self::MisalignedHint | Self::HintOutOfRange => ErrorStatus::InvalidPtr,
+            Self::ZeroPageCount | Self::PageCountOverflow => ErrorStatus::InvalidSize,
+            Self::NegativeOffset | Self::MisalignedOffset => ErrorStatus::InvalidOffset,
+            Self::InvalidResource => ErrorStatus::UnknownResource,
+            Self::NonZeroReserved => ErrorStatus::InvalidArgument,
+        }
+    }
+}
+
+impl RawMemMapConfig {
+    /// The revision of configurations created by this version of the ABI
+    pub const LATEST_REVISION: u32 = 0;
+
+    /// Creates a new memory map configuration with the latest revision from raw FFI values
+    #[inline(always)]
+    pub const fn new_from_raw(
+        addr_hint: *const (),
+        page_count: usize,
+        guard_pages_count: usize,
+        resource_to_map: usize,
+        resource_off: isize,
+    ) -> Self {
+        Self {
+            revision: Self::LATEST_REVISION,
+            _reserved: 0,
+            addr_hint,
+            page_count,
+            guard_pages_count,
+            resource_to_map,
+            resource_off,
+        }
+    }
+
+    /// Creates a new memory map configuration with the latest revision,
+    /// the resource is only mapped if [`MemMapFlags::MAP_RESOURCE`] is given to the syscall
+    #[inline]
+    pub const fn new(
+        addr_hint: Option<*const ()>,
+        page_count: usize,
+        guard_pages_count: usize,
+        resource: Option<(u32, isize)>,
+    ) -> Self {
+        let addr_hint = match addr_hint {
+            Some(addr) => addr,
+            None => core::ptr::null(),
+        };
+        let (resource_to_map, resource_off) = match resource {
+            Some((resource, off)) => (resource as usize, off),
+            None => (0, 0),
+        };
+
+        Self::new_from_raw(
+            addr_hint,
+            page_count,
+            guard_pages_count,
+            resource_to_map,
+            resource_off,
+        )
+    }
+
+    /// Validates the configuration given the flags it is going to be used with and the page sizes supported by the kernel,
+    /// returns the selected page size on success
+    ///
+    /// The resource and its offset are only checked if [`MemMapFlags::MAP_RESOURCE`] is given.
+    pub fn validate(
+        &self,
+        flags: MemMapFlags,
+        page_sizes: &PageSizes,
+    ) -> Result<usize, MemMapConfigError> {
+        if self.revision > Self::LATEST_REVISION {
+            return Err(MemMapConfigError::UnknownRevision);
+        }
+
+        if self._reserved != 0 {
+            return Err(MemMapConfigError::NonZeroReserved);
+        }
+
+        let page_size = page_sizes
+            .page_size_for(flags)
+            .ok_or(MemMapConfigError::UnsupportedPageSize)?;
+
+        let addr_hint = self.addr_hint as usize;
+        if !addr_hint.is_multiple_of(page_size) {
+            return Err(MemMapConfigError::MisalignedHint);
+        }
+
+        if self.page_count == 0 {
+            return Err(MemMapConfigError::ZeroPageCount);
+        }
+
+        let guard_bytes = self
+            .guard_pages_count
+            .checked_mul(page_size)
+            .ok_or(MemMapConfigError::PageCountOverflow)?;
+        let area_bytes = self
+            .page_count
+            .checked_mul(page_size)
+            .ok_or(MemMapConfigError::PageCountOverflow)?;
+        let area_and_trailing_guard = area_bytes
+            .checked_add(guard_bytes)
+            .ok_or(MemMapConfigError::PageCountOverflow)?;
+        area_and_trailing_guard
+            .checked_add(guard_bytes)
+            .ok_or(MemMapConfigError::PageCountOverflow)?;
+
+        if addr_hint != 0 {
+            addr_hint
+                .checked_sub(guard_bytes)
+                .ok_or(MemMapConfigError::HintOutOfRange)?;
+            addr_hint
+                .checked_add(area_and_trailing_guard)
+                .ok_or(MemMapConfigError::HintOutOfRange)?;
+        }
+
+        if flags.contains(MemMapFlags::MAP_RESOURCE) {
+            if self.resource_to_map > u32::MAX as usize {
+                return Err(MemMapConfigError::InvalidResource);
+            }
+
+            if self.resource_off < 0 {
+                return Err(MemMapConfigError::NegativeOffset);
+            }
+
+            if !(self.resource_off as usize).is_multiple_of(page_size) {
+                return Err(MemMapConfigError::MisalignedOffset);
+            }
+        }
+
+        Ok(page_size)
+    }
+}
+
+/// The page sizes supported by the kernel for [`crate::syscalls::SyscallTable::SysMemMap`] in bytes, a size of 0 means that page size isn't supported
+///
+/// Returned by [`crate::syscalls::SyscallTable::SysMemPageSizes`] and passed in [`crate::process::AbiStructures::page_sizes`]
+///
+/// On x86_64 large pages are 2MiB and huge pages are 1GiB,
+/// On aarch64 with a 4KiB base page size large pages are 64KiB (contiguous pages) and huge pages are 2MiB
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[repr(C)]
+pub struct PageSizes {
+    /// The base page size, always supported
+    pub base: usize,
+    /// The size of pages mapped with [`MemMapFlags::LARGE_PAGES`]
+    pub large: usize,
+    /// The size of pages mapped with [`MemMapFlags::HUGE_PAGES`]
+    pub huge: usize,
+}
+
+impl PageSizes {
+    pub const fn new(base: usize, large: usize, huge: usize) -> Self {
+        Self { base, large, huge }
+    }
+
+    /// Returns the page size selected by the given flags or None if it isn't supported,
+    /// also returns None if both [`MemMapFlags::LARGE_PAGES`] and [`MemMapFlags::HUGE_PAGES`] are given
+    pub const fn page_size_for(&self, flags: MemMapFlags) -> Option<usize> {
+        let size = match (
+            flags.contains(MemMapFlags::LARGE_PAGES),
+            flags.contains(MemMapFlags::HUGE_PAGES),
+        ) {
+            (false, false) => self.base,
+            (true, false) => self.large,
+            (false, true) => self.huge,
+            (true, true) => 0,
+        };
+
+        if size == 0 { None } else { Some(size) }
+    }
+}
+
 /// Flags passed to [`crate::syscalls::SyscallTable::SysMemMap`]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 #[repr(transparent)]
-pub struct MemMapFlags(u8);
+pub struct MemMapFlags(u16);
 
 impl MemMapFlags {
     pub const NONE: Self = Self(0);
@@ -33,14 +251,28 @@ impl MemMapFlags {
     /// By default the kernel will lazy allocate requested memory.
     /// If this flag is set the kernel will imeddiately allocate memory.
     pub const POPULATE: Self = Self(1 << 4);
+    /// Binds the lifetime of the mapping's resource to the calling thread instead of the process,
+    /// when the thread exits the resource is dropped and the memory is unmapped, other threads may still access the memory until then
+    pub const THREAD_LOCAL: Self = Self(1 << 5);
+    /// Writes to a mapped resource are shared with every other shared mapping of it and written back to the resource on sync, the default with [`Self::MAP_RESOURCE`]
+    pub const SHARED: Self = Self(1 << 6);
+    /// Writes to a mapped resource are copy on write, private to this mapping and never written back to the resource,
+    /// for example used to map executable segments that are relocated in place
+    pub const PRIVATE: Self = Self(1 << 7);
+    /// Map using [`PageSizes::large`] pages instead of base pages,
+    /// errors with [`crate::errors::ErrorStatus::NotSupported`] if large pages aren't supported
+    pub const LARGE_PAGES: Self = Self(1 << 8);
+    /// Map using [`PageSizes::huge`] pages instead of base pages, cannot be combined with [`Self::LARGE_PAGES`],
+    /// errors with [`crate::errors::ErrorStatus::NotSupported`] if huge pages aren't supported
+    pub const HUGE_PAGES: Self = Self(1 << 9);
 }
 
 impl MemMapFlags {
-    pub const fn from_bits(bits: u8) -> Self {
+    pub const fn from_bits(bits: u16) -> Self {
         Self(bits)
     }
 
-    pub const fn to_bits(self) -> u8 {
+    pub const fn to_bits(self) -> u16 {
         self.0
     }
 
@@ -63,6 +295,48 @@ impl BitAnd for MemMapFlags {
     }
 }
 
+/// Flags passed to [`crate::syscalls::SyscallTable::SysMemRemap`], by default the mapping is only resized in place
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[repr(transparent)]
+pub struct MemRemapFlags(u8);
+
+impl MemRemapFlags {
+    pub const NONE: Self = Self(0);
+    /// Allows the kernel to move the mapping to a new address if it cannot be grown in place
+    pub const MAY_MOVE: Self = Self(1 << 0);
+    /// Move the mapping to exactly the given new address, requires [`Self::MAY_MOVE`],
+    /// same as [`MemMapFlags::FIXED`] the new area cannot collide with existing mappings
+    pub const FIXED: Self = Self(1 << 1);
+}
+
+impl MemRemapFlags {
+    pub const fn from_bits(bits: u8) -> Self {
+        Self(bits)
+    }
+
+    pub const fn to_bits(self) -> u8 {
+        self.0
+    }
+
+    pub const fn contains(self, other: Self) -> bool {
+        (self.0 & other.0) == other.0
+    }
+}
+
+impl BitOr for MemRemapFlags {
+    type Output = Self;
+    fn bitor(self, rhs: Self) -> Self::Output {
+        Self(self.0 | rhs.0)
+    }
+}
+
+impl BitAnd for MemRemapFlags {
+    type Output = Self;
+    fn bitand(self, rhs: Self) -> Self::Output {
+        Self(self.0 & rhs.0)
+    }
+}
+
 /// Flags passed to [`crate::syscalls::SyscallTable::SysMemProtect`]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 #[repr(transparent)]
@@ -106,6 +380,93 @@ impl BitAnd for MemFlags {
     }
 }
 
+/// Flags passed to [`crate::syscalls::SyscallTable::SysMemSync`]
+///
+/// Exactly one of [`Self::SYNC`] or [`Self::ASYNC`] must be given, [`Self::INVALIDATE`] can be combined with either,
+/// otherwise [`crate::syscalls::SyscallTable::SysMemSync`] errors with [`crate::errors::ErrorStatus::InvalidArgument`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[repr(transparent)]
+pub struct MemSyncFlags(u8);
+
+impl MemSyncFlags {
+    pub const NONE: Self = Self(0);
+    /// Write the modified pages back to the mapped resource and wait for the writeback to complete
+    pub const SYNC: Self = Self(1 << 0);
+    /// Only initiate the writeback of the modified pages and return without waiting
+    pub const ASYNC: Self = Self(1 << 1);
+    /// Drop the cached pages of the range after writing them back, so that the next access reads the resource's current contents
+    pub const INVALIDATE: Self = Self(1 << 2);
+}
+
+impl MemSyncFlags {
+    pub const fn from_bits(bits: u8) -> Self {
+        Self(bits)
+    }
+
+    pub const fn to_bits(self) -> u8 {
+        self.0
+    }
+
+    pub const fn contains(self, other: Self) -> bool {
+        (self.0 & other.0) == other.0
+    }
+}
+
+impl BitOr for MemSyncFlags {
+    type Output = Self;
+    fn bitor(self, rhs: Self) -> Self::Output {
+        Self(self.0 | rhs.0)
+    }
+}
+
+impl BitAnd for MemSyncFlags {
+    type Output = Self;
+    fn bitand(self, rhs: Self) -> Self::Output {
+        Self(self.0 & rhs.0)
+    }
+}
+
+/// An advice about how a range of memory is going to be used, passed to [`crate::syscalls::SyscallTable::SysMemAdvise`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u32)]
+pub enum MemAdvice {
+    /// No special treatment, resets [`Self::Sequential`] and [`Self::Random`]
+    Normal = 0,
+    /// Drop the pages immediately, keeping the address range reserved,
+    /// the next access reads zeros for anonymous memory or the resource's contents for resource mappings
+    DontNeed = 1,
+    /// The pages' contents are no longer needed, the kernel may drop them lazily when under memory pressure,
+    /// until then they keep their contents, writing to a page cancels the advice for that page.
+    /// Only valid for anonymous private memory
+    Free = 2,
+    /// The pages are going to be accessed soon, start populating them in the background, the lazy counterpart of [`MemMapFlags::POPULATE`]
+    WillNeed = 3,
+    /// The pages of a resource mapping are going to be accessed sequentially, read ahead aggressively and drop pages after they are accessed
+    Sequential = 4,
+    /// The pages of a resource mapping are going to be accessed randomly, don't read ahead
+    Random = 5,
+    /// Exclude the pages from core dumps and crash reports
+    NoDump = 6,
+    /// Cancel [`Self::NoDump`]
+    DoDump = 7,
+}
+
+impl MemAdvice {
+    pub const fn try_from(value: u32) -> Option<Self> {
+        match value {
+            0 => Some(Self::Normal),
+            1 => Some(Self::DontNeed),
+            2 => Some(Self::Free),
+            3 => Some(Self::WillNeed),
+            4 => Some(Self::Sequential),
+            5 => Some(Self::Random),
+            6 => Some(Self::NoDump),
+            7 => Some(Self::DoDump),
+            _ => None,
+        }
+    }
+}
+
 /// Flags passed to [`crate::syscalls::SyscallTable::SysMemShmCreate`] and [`crate::syscalls::SyscallTable::SysMemShmOpen`]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 #[repr(transparent)]
@@ -113,6 +474,14 @@ pub struct ShmFlags(u32);
 
 impl ShmFlags {
     pub const NONE: Self = Self(0);
+    /// The opened Resource can only be mapped for reading, mapping it with [`MemMapFlags::WRITE`] errors with [`crate::errors::ErrorStatus::MissingPermissions`]
+    pub const READ_ONLY: Self = Self(1 << 0);
+    /// Binds the lifetime of the created descriptor or the opened Resource to the calling thread instead of the process
+    pub const THREAD_LOCAL: Self = Self(1 << 1);
+    /// For [`crate::syscalls::SyscallTable::SysMemShmOpenNamed`], create the descriptor if it doesn't exist otherwise open the existing one
+    pub const CREATE: Self = Self(1 << 2);
+    /// Combined with [`Self::CREATE`], fail with [`crate::errors::ErrorStatus::AlreadyExists`] if the descriptor already exists instead of opening it
+    pub const EXCLUSIVE: Self = Self(1 << 3);
 }
 
 impl ShmFlags {
@@ -142,3 +511,178 @@ impl BitAnd for ShmFlags {
         Self(self.0 & rhs.0)
     }
 }
+
+/// Flags passed to [`crate::syscalls::SyscallTable::SysMemFileCreate`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[repr(transparent)]
+pub struct MemFileFlags(u32);
+
+impl MemFileFlags {
+    pub const NONE: Self = Self(0);
+}
+
+impl MemFileFlags {
+    pub const fn from_bits(bits: u32) -> Self {
+        Self(bits)
+    }
+
+    pub const fn to_bits(self) -> u32 {
+        self.0
+    }
+
+    pub const fn contains(self, other: Self) -> bool {
+        (self.0 & other.0) == other.0
+    }
+}
+
+impl BitOr for MemFileFlags {
+    type Output = Self;
+    fn bitor(self, rhs: Self) -> Self::Output {
+        Self(self.0 | rhs.0)
+    }
+}
+
+impl BitAnd for MemFileFlags {
+    type Output = Self;
+    fn bitand(self, rhs: Self) -> Self::Output {
+        Self(self.0 & rhs.0)
+    }
+}
+
+/// The kind of object backing a memory mapping, see [`MemMapInfo`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub enum MemMapBacking {
+    /// Anonymous memory mapped with [`crate::syscalls::SyscallTable::SysMemMap`] without [`MemMapFlags::MAP_RESOURCE`]
+    Anonymous = 0,
+    /// A file in the VFS, including the program's own executable
+    File = 1,
+    Device = 2,
+    /// A shared memory descriptor opened with [`crate::syscalls::SyscallTable::SysMemShmOpen`]
+    SharedMemory = 3,
+    /// An anonymous memory backed file created with [`crate::syscalls::SyscallTable::SysMemFileCreate`]
+    MemFile = 4,
+    /// The data area managed with [`crate::syscalls::SyscallTable::SysPSbrk`]
+    Heap = 5,
+    /// The stack of a thread
+    Stack = 6,
+}
+
+impl MemMapBacking {
+    pub const fn try_from(value: u8) -> Option<Self> {
+        match value {
+            0 => Some(Self::Anonymous),
+            1 => Some(Self::File),
+            2 => Some(Self::Device),
+            3 => Some(Self::SharedMemory),
+            4 => Some(Self::MemFile),
+            5 => Some(Self::Heap),
+            6 => Some(Self::Stack),
+            _ => None,
+        }
+    }
+}
+
+/// Describes a single memory mapping of a process, returned by [`crate::syscalls::SyscallTable::SysMemMappings`]
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[repr(C)]
+pub struct MemMapInfo {
+    /// The start address of the mapping, not including guard pages
+    pub start: usize,
+    /// The amount of mapped pages, not including guard pages
+    pub page_count: usize,
+    /// The size of a single page in the mapping, see [`PageSizes`]
+    pub page_size: usize,
+    /// The amount of guard pages before and after the mapping, same as [`RawMemMapConfig::guard_pages_count`]
+    pub guard_pages_count: usize,
+    /// The amount of pages currently resident in physical memory
+    pub resident_pages: usize,
+    /// The offset into the backing resource, 0 for mappings that aren't backed by a resource
+    pub resource_off: isize,
+    /// The flags the mapping was created with
+    pub flags: MemMapFlags,
+    /// The current protection of the mapping
+    pub protection: MemFlags,
+    pub backing: MemMapBacking,
+    /// The ID of the Resource that describes the memory map in the inspected process, [`u32::MAX`] for mappings without one such as the stack
+    pub mapping_resource: u32,
+    /// The length of [`Self::path`]
+    pub path_length: usize,
+    /// The path of the backing object for [`MemMapBacking::File`] and [`MemMapBacking::Device`] mappings,
+    /// or the debug name of [`MemMapBacking::MemFile`] mappings, truncated to [`consts::MAX_PATH_LENGTH`] bytes
+    pub path: [u8; consts::MAX_PATH_LENGTH],
+}
+
+impl MemMapInfo {
+    pub const fn new(
+        start: usize,
+        page_count: usize,
+        page_size: usize,
+        flags: MemMapFlags,
+        protection: MemFlags,
+        backing: MemMapBacking,
+    ) -> Self {
+        Self {
+            start,
+            page_count,
+            page_size,
+            guard_pages_count: 0,
+            resident_pages: 0,
+            resource_off: 0,
+            flags,
+            protection,
+            backing,
+            mapping_resource: u32::MAX,
+            path_length: 0,
+            path: [0u8; consts::MAX_PATH_LENGTH],
+        }
+    }
+
+    /// Sets the path of the backing object, truncated to [`consts::MAX_PATH_LENGTH`] bytes
+    pub fn with_path(mut self, path: &str) -> Self {
+        let path_length = path.len().min(consts::MAX_PATH_LENGTH);
+        self.path[..path_length].copy_from_slice(&path.as_bytes()[..path_length]);
+        self.path[path_length..].fill(0);
+        self.path_length = path_length;
+        self
+    }
+
+    /// Returns the bytes of the path of the backing object, should be valid UTF-8 unless it was truncated in the middle of a character
+    pub fn path(&self) -> &[u8] {
+        &self.path[..self.path_length.min(consts::MAX_PATH_LENGTH)]
+    }
+}
+
+/// Memory usage of a single process in bytes, returned by [`crate::syscalls::SyscallTable::SysPMemStats`]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[repr(C)]
+pub struct ProcessMemStats {
+    /// Memory currently resident in physical memory, including shared memory
+    pub resident: usize,
+    /// The total size of the address space, including memory that was never touched
+    pub virtual_size: usize,
+    /// Resident memory shared with other processes, such as shared memory descriptors and shared resource mappings
+    pub shared: usize,
+    /// The current data break as returned by [`crate::syscalls::SyscallTable::SysPSbrk`]
+    pub heap_break: usize,
+    /// The highest [`Self::resident`] value reached during the process's lifetime
+    pub peak_resident: usize,
+    /// Memory locked with [`crate::syscalls::SyscallTable::SysMemLock`]
+    pub locked: usize,
+    /// The maximum amount of memory the process can lock, [`usize::MAX`] if it isn't limited
+    pub lock_limit: usize,
+}
+
+/// System-wide memory usage in bytes, returned by [`crate::syscalls::SyscallTable::SysMemStats`]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[repr(C)]
+pub struct SystemMemStats {
+    /// The total usable physical memory
+    pub total: usize,
+    /// Physical memory that isn't used for anything
+    pub free: usize,
+    /// Physical memory used for caching files and devices that can be reclaimed when under memory pressure
+    pub cached: usize,
+    /// Physical memory used by the kernel's heap
+    pub kernel_heap: usize,
+}
@@ -2,11 +2,66 @@
 use core::num::NonZero;
 use core::ops::BitOr;
 
+use crate::errors::ErrorStatus;
 use crate::ffi::num::ShouldNotBeZero;
 use crate::ffi::option::{COption, OptZero};
 use crate::ffi::ptr::FFINonNull;
 use crate::ffi::slice::Slice;
 use crate::ffi::str::Str;
+use crate::fs::{Gid, Uid};
+use crate::mem::PageSizes;
+use crate::signal::Signal;
+
+/// A sentinel PID that refers to the calling process, accepted by syscalls that inspect a process such as [`crate::syscalls::SyscallTable::SysMemMappings`]
+pub const SELF_PID: u32 = u32::MAX;
+
+/// Set in the exit code returned by [`crate::syscalls::SyscallTable::SysPWait`] and [`crate::syscalls::SyscallTable::SysPTryCleanUp`]
+/// if the process was killed with [`crate::syscalls::SyscallTable::SysPKill`] (or its last thread with [`crate::syscalls::SyscallTable::SysTKill`]),
+/// the low 16 bits are the [`ErrorStatus`] reason code, see [`ExitStatus`]
+pub const EXIT_KILLED: usize = 1 << (usize::BITS - 1);
+/// Set in the exit code returned by [`crate::syscalls::SyscallTable::SysPWait`] and [`crate::syscalls::SyscallTable::SysPTryCleanUp`]
+/// if the process was terminated by the default action of a [`Signal`] (see [`Signal::terminates_by_default`]),
+/// the low 32 bits are the signal number, see [`ExitStatus`]
+pub const EXIT_SIGNALED: usize = 1 << (usize::BITS - 2);
+/// The bits of an exit code reserved by the kernel to describe how the process exited,
+/// they are cleared from the exit code passed to [`crate::syscalls::SyscallTable::SysPExit`] and [`crate::syscalls::SyscallTable::SysTExit`]
+pub const EXIT_RESERVED_MASK: usize = EXIT_KILLED | EXIT_SIGNALED;
+
+/// A decoded exit code as returned by [`crate::syscalls::SyscallTable::SysPWait`] and [`crate::syscalls::SyscallTable::SysPTryCleanUp`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExitStatus {
+    /// The process exited on its own with the given exit code
+    Exited(usize),
+    /// The process was killed with the given reason
+    Killed(ErrorStatus),
+    /// The process was terminated by the default action of the given signal
+    Signaled(Signal),
+}
+
+impl ExitStatus {
+    /// Decodes a raw exit code, unknown reason codes and unknown signals are decoded as [`Self::Killed`] with [`ErrorStatus::Unknown`]
+    pub const fn from_raw(code: usize) -> Self {
+        if code & EXIT_KILLED != 0 {
+            Self::Killed(ErrorStatus::from_u16(code as u16))
+        } else if code & EXIT_SIGNALED != 0 {
+            match Signal::try_from(code as u32) {
+                Some(signal) => Self::Signaled(signal),
+                None => Self::Killed(ErrorStatus::Unknown),
+            }
+        } else {
+            Self::Exited(code & !EXIT_RESERVED_MASK)
+        }
+    }
+
+    /// Encodes into a raw exit code, exit codes that have any of the bits in [`EXIT_RESERVED_MASK`] set are truncated
+    pub const fn into_raw(self) -> usize {
+        match self {
+            Self::Exited(code) => code & !EXIT_RESERVED_MASK,
+            Self::Killed(reason) => EXIT_KILLED | reason as u16 as usize,
+            Self::Signaled(signal) => EXIT_SIGNALED | signal as u32 as usize,
+        }
+    }
+}
 
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
 #[repr(C)]
@@ -29,6 +84,33 @@ pub struct AbiStructures {
     pub at_phnum: usize,
     /// Base address of interpreter.
     pub at_base: usize,
+    /// The page sizes supported by the kernel, same as [`crate::syscalls::SyscallTable::SysMemPageSizes`]
+    pub page_sizes: PageSizes,
+    /// The credentials the process started with, same as [`crate::syscalls::SyscallTable::SysPGetCredentials`] until they are changed
+    pub credentials: ProcessCredentials,
+}
+
+/// The user and group a process runs as, [`crate::fs::FileMode`] checks and privileged syscalls are done against them
+///
+/// A spawned process inherits the credentials of its parent regardless of the [`SpawnFlags`],
+/// unless the executable has [`crate::fs::FileMode::SETUID`] (or [`crate::fs::FileMode::SETGID`]) in which case the child runs as the executable's owner (or group).
+/// Threads share the credentials of their process.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[repr(C)]
+pub struct ProcessCredentials {
+    pub uid: Uid,
+    pub gid: Gid,
+}
+
+impl ProcessCredentials {
+    pub const fn new(uid: Uid, gid: Gid) -> Self {
+        Self { uid, gid }
+    }
+
+    /// Returns true if the process runs as [`Uid::ROOT`]
+    pub const fn is_root(&self) -> bool {
+        self.uid.get() == Uid::ROOT.get()
+    }
 }
 
 // Resources are actually 32-bit now but if i change this everything will break
@@ -73,6 +155,8 @@ impl ProcessStdio {
 /// Flags for the [crate::syscalls::SyscallTable::SysPSpawn] syscall
 pub struct SpawnFlags(u8);
 impl SpawnFlags {
+    /// Clones all the resources of the parent process into the child process,
+    /// clones share state tied to the open file such as advisory locks, just like [crate::syscalls::SyscallTable::SysRClone]
     pub const CLONE_RESOURCES: Self = Self(1 << 0);
     pub const CLONE_CWD: Self = Self(1 << 1);
     pub const EMPTY: Self = Self(0);
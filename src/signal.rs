@@ -0,0 +1,252 @@
+//! Signals (asynchronous process notifications) related ABI structures
+use core::ops::BitOr;
+
+/// A signal number
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u32)]
+pub enum Signal {
+    /// An interrupt request from the user, usually sent by a shell on Ctrl-C to its foreground job
+    Interrupt = 1,
+    /// A request to terminate, unlike [`crate::syscalls::SyscallTable::SysPKill`] it can be handled to shut down gracefully
+    Terminate = 2,
+    /// A child process exited, [`SignalInfo::status`] contains its exit code (see [`crate::process::ExitStatus`]), ignored by default
+    ChildExited = 3,
+    /// Meaning defined by the user
+    User1 = 4,
+    /// Meaning defined by the user
+    User2 = 5,
+    /// Attempt to write to a connection that was closed by the other side, the write still errors with [`crate::errors::ErrorStatus::ConnectionClosed`]
+    BrokenPipe = 6,
+    /// A timer set with [`crate::syscalls::SyscallTable::SysPAlarm`] expired
+    Alarm = 7,
+}
+
+impl Signal {
+    pub const fn try_from(value: u32) -> Option<Self> {
+        match value {
+            1 => Some(Self::Interrupt),
+            2 => Some(Self::Terminate),
+            3 => Some(Self::ChildExited),
+            4 => Some(Self::User1),
+            5 => Some(Self::User2),
+            6 => Some(Self::BrokenPipe),
+            7 => Some(Self::Alarm),
+            _ => None,
+        }
+    }
+
+    /// Returns true if the default action of the signal is to terminate the process, otherwise it is ignored by default
+    ///
+    /// A process terminated by a signal is cleaned up the same as if it was killed with [`crate::syscalls::SyscallTable::SysPKill`],
+    /// its exit code is the signal number with [`crate::process::EXIT_SIGNALED`] set, see [`crate::process::ExitStatus::Signaled`]
+    pub const fn terminates_by_default(self) -> bool {
+        !matches!(self, Self::ChildExited)
+    }
+}
+
+/// A set of [`Signal`]s, used as a signal mask
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct SignalSet(u64);
+
+impl SignalSet {
+    pub const EMPTY: Self = Self(0);
+    pub const ALL: Self = Self(((1 << 8) - 1) & !1);
+
+    /// Returns a set that only contains the given signal
+    pub const fn of(signal: Signal) -> Self {
+        Self(1 << signal as u32)
+    }
+
+    pub const fn from_bits(bits: u64) -> Self {
+        Self(bits)
+    }
+
+    pub const fn to_bits(self) -> u64 {
+        self.0
+    }
+
+    pub const fn contains(self, other: Self) -> bool {
+        (self.0 & other.0) == other.0
+    }
+
+    pub const fn contains_signal(self, signal: Signal) -> bool {
+        self.contains(Self::of(signal))
+    }
+
+    pub const fn union(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+
+    pub const fn difference(self, other: Self) -> Self {
+        Self(self.0 & !other.0)
+    }
+}
+
+impl BitOr for SignalSet {
+    type Output = Self;
+    fn bitor(self, rhs: Self) -> Self::Output {
+        Self(self.0 | rhs.0)
+    }
+}
+
+/// Describes how [`crate::syscalls::SyscallTable::SysTSigMask`] changes the calling thread's signal mask
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u32)]
+pub enum SigMaskHow {
+    /// Add the given signals to the mask
+    Block = 0,
+    /// Remove the given signals from the mask
+    Unblock = 1,
+    /// Replace the mask with the given signals
+    Set = 2,
+}
+
+impl SigMaskHow {
+    pub const fn try_from(value: u32) -> Option<Self> {
+        match value {
+            0 => Some(Self::Block),
+            1 => Some(Self::Unblock),
+            2 => Some(Self::Set),
+            _ => None,
+        }
+    }
+}
+
+/// Information about a delivered signal
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(C)]
+pub struct SignalInfo {
+    pub signal: Signal,
+    /// The PID of the process that sent the signal, the PID of the exited child for [`Signal::ChildExited`],
+    /// or the PID of the receiving process for signals raised by the kernel such as [`Signal::Alarm`]
+    pub sender_pid: u32,
+    /// The raw exit code of the child for [`Signal::ChildExited`] (see [`crate::process::ExitStatus`]), 0 otherwise
+    pub status: usize,
+}
+
+impl SignalInfo {
+    pub const fn new(signal: Signal, sender_pid: u32, status: usize) -> Self {
+        Self {
+            signal,
+            sender_pid,
+            status,
+        }
+    }
+}
+
+/// Passed to signal handlers, describes the signal and the state of the interrupted thread
+///
+/// The handler may change the context, the thread resumes with the changed context once the handler returns
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(C)]
+pub struct SignalContext {
+    pub info: SignalInfo,
+    /// The instruction pointer of the interrupted thread
+    pub ip: usize,
+    /// The stack pointer of the interrupted thread
+    pub sp: usize,
+    /// The signal mask of the interrupted thread, restored once the handler returns
+    pub mask: SignalSet,
+}
+
+/// A userspace signal handler, runs on a thread that doesn't block the signal,
+/// the handler's return address is a kernel provided trampoline that resumes the thread using the (possibly changed) context
+pub type SignalHandler = extern "C" fn(signal: Signal, context: &mut SignalContext);
+
+/// The action taken when a signal is delivered
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u32)]
+pub enum SignalActionKind {
+    /// Take the default action of the signal, see [`Signal::terminates_by_default`]
+    Default = 0,
+    /// Discard the signal
+    Ignore = 1,
+    /// Invoke [`RawSignalAction::handler`]
+    Handle = 2,
+}
+
+impl SignalActionKind {
+    pub const fn try_from(value: u32) -> Option<Self> {
+        match value {
+            0 => Some(Self::Default),
+            1 => Some(Self::Ignore),
+            2 => Some(Self::Handle),
+            _ => None,
+        }
+    }
+}
+
+/// Flags for a [`RawSignalAction`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct SignalActionFlags(u32);
+
+impl SignalActionFlags {
+    pub const NONE: Self = Self(0);
+    /// Run the handler on the thread's alternate stack set with [`crate::syscalls::SyscallTable::SysTSetAltStack`] if there is one
+    pub const ON_ALT_STACK: Self = Self(1 << 0);
+    /// Restart blocking syscalls interrupted by the signal once the handler returns,
+    /// by default they return [`crate::errors::ErrorStatus::ForceTerminated`]
+    pub const RESTART: Self = Self(1 << 1);
+
+    pub const fn from_bits(bits: u32) -> Self {
+        Self(bits)
+    }
+
+    pub const fn to_bits(self) -> u32 {
+        self.0
+    }
+
+    pub const fn contains(self, other: Self) -> bool {
+        (self.0 & other.0) == other.0
+    }
+}
+
+impl BitOr for SignalActionFlags {
+    type Output = Self;
+    fn bitor(self, rhs: Self) -> Self::Output {
+        Self(self.0 | rhs.0)
+    }
+}
+
+/// Passed to [`crate::syscalls::SyscallTable::SysPSigAction`] to describe how a signal is handled
+#[repr(C)]
+pub struct RawSignalAction {
+    pub kind: SignalActionKind,
+    pub flags: SignalActionFlags,
+    /// Required if kind is [`SignalActionKind::Handle`], ignored otherwise
+    pub handler: Option<SignalHandler>,
+    /// Signals added to the thread's mask while the handler runs, the handled signal is always added
+    pub mask: SignalSet,
+}
+
+impl RawSignalAction {
+    pub const DEFAULT: Self = Self {
+        kind: SignalActionKind::Default,
+        flags: SignalActionFlags::NONE,
+        handler: None,
+        mask: SignalSet::EMPTY,
+    };
+
+    pub const IGNORE: Self = Self {
+        kind: SignalActionKind::Ignore,
+        flags: SignalActionFlags::NONE,
+        handler: None,
+        mask: SignalSet::EMPTY,
+    };
+
+    /// Creates a new action that invokes the given handler
+    pub const fn new_handler(
+        handler: SignalHandler,
+        flags: SignalActionFlags,
+        mask: SignalSet,
+    ) -> Self {
+        Self {
+            kind: SignalActionKind::Handle,
+            flags,
+            handler: Some(handler),
+            mask,
+        }
+    }
+}
@@ -135,6 +135,13 @@ use core::{
     ptr::NonNull,
 };
 
+use crate::ffi::{
+    io::{IoSlice, IoSliceMut},
+    option::OptZero,
+    ptr::FFINonNull,
+    slice::Slice,
+};
+
 /// Domain given to [`crate::syscalls::SyscallTable::SysSockCreate`].
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 #[repr(transparent)]
@@ -251,3 +258,53 @@ impl Not for SockMsgFlags {
         Self(!self.0)
     }
 }
+
+/// Describes a message passed to [`crate::syscalls::SyscallTable::SysSockSendMsg`] and [`crate::syscalls::SyscallTable::SysSockRecvMsg`]
+///
+/// `B` is the type of the buffers, [`IoSlice`] when sending and [`IoSliceMut`] when receiving,
+/// see [`SockSendMsgHeader`] and [`SockRecvMsgHeader`].
+#[repr(C)]
+pub struct SockMsgHeader<B> {
+    /// The target address when sending or a buffer to put the source address in when receiving,
+    /// None to send to the connected socket or to ignore the source address
+    pub addr: OptZero<FFINonNull<SocketAddr>>,
+    /// The length of the structure pointed to by [`Self::addr`], when receiving it is updated with the actual length of the source address
+    pub addr_len: usize,
+    /// The buffers to gather the message from when sending or to scatter it into when receiving
+    pub iov: Slice<B>,
+}
+
+/// A [`SockMsgHeader`] passed to [`crate::syscalls::SyscallTable::SysSockSendMsg`], the buffers are only read from
+pub type SockSendMsgHeader = SockMsgHeader<IoSlice>;
+/// A [`SockMsgHeader`] passed to [`crate::syscalls::SyscallTable::SysSockRecvMsg`], the buffers are written to
+pub type SockRecvMsgHeader = SockMsgHeader<IoSliceMut>;
+
+impl SockMsgHeader<IoSlice> {
+    /// Creates a new message header to send from the given buffers
+    pub const fn new_send(
+        addr: OptZero<FFINonNull<SocketAddr>>,
+        addr_len: usize,
+        iov: &[IoSlice],
+    ) -> Self {
+        Self {
+            addr,
+            addr_len,
+            iov: Slice::from_slice(iov),
+        }
+    }
+}
+
+impl SockMsgHeader<IoSliceMut> {
+    /// Creates a new message header to receive into the given buffers
+    pub const fn new_recv(
+        addr: OptZero<FFINonNull<SocketAddr>>,
+        addr_len: usize,
+        iov: &mut [IoSliceMut],
+    ) -> Self {
+        Self {
+            addr,
+            addr_len,
+            iov: Slice::from_slice_mut(iov),
+        }
+    }
+}
@@ -2,15 +2,63 @@
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 #[repr(u16)]
 pub enum SyscallTable {
+    /// Exits the current process with the given exit code, the bits in [`crate::process::EXIT_RESERVED_MASK`] are cleared from it
     SysPExit = 0,
     /// Yields execution to the next thread in the current CPU
     SysTYield = 1,
-    /// Opens a file or directory with all permissions
+    /// Opens a file or directory for reading and writing
+    ///
+    /// Despite the name this is subject to the same [`crate::fs::FileMode`] checks as [`SyscallTable::SysFSOpen`] with [`crate::fs::OpenOptions::READ`] and [`crate::fs::OpenOptions::WRITE`]
     SysFSOpenAll = 2,
     /// Opens a file or directory with given mode (permissions and flags)
+    ///
+    /// The requested permissions are checked against the object's [`crate::fs::FileAttr::mode`], using the owner bits if the calling process's user owns the object,
+    /// the group bits if the calling process's group owns it and the other bits otherwise (see [`crate::process::ProcessCredentials`]),
+    /// reading requires the read bit, writing (including [`crate::fs::OpenOptions::WRITE_TRUNCATE`]) requires the write bit,
+    /// creating an object requires the write bit of the parent directory, and resolving a path requires the exec bit of every directory in it.
+    ///
+    /// Processes running as [`crate::fs::Uid::ROOT`] bypass all the checks, failing any check errors with [`crate::errors::ErrorStatus::MissingPermissions`].
+    ///
+    /// Created objects get the default [`crate::fs::FileMode`] for their kind and are owned by the calling process's user and group
     SysFSOpen = 25,
     /// Deletes a path
+    ///
+    /// Removes the directory entry the path refers to and decrements the object's [`crate::fs::FileAttr::link_count`],
+    /// the object's data is only freed once the link count reaches zero and no resource refers to it,
+    /// so open resources keep working on unlinked data until they are destroyed.
+    ///
+    /// Errors with [`crate::errors::ErrorStatus::DirectoryNotEmpty`] if the path is a directory that isn't empty
     SysFSRemovePath = 28,
+    /// Creates a hard link, an additional name for an existing file
+    ///
+    /// Takes the path of the existing file then the new path, both refer to the same object afterwards and the object's [`crate::fs::FileAttr::link_count`] is incremented.
+    ///
+    /// Errors with:
+    /// - [`crate::errors::ErrorStatus::CrossDriveLink`] if the paths are on different drives
+    /// - [`crate::errors::ErrorStatus::AlreadyExists`] if the new path already exists
+    /// - [`crate::errors::ErrorStatus::NotAFile`] if the existing path is a directory, directories cannot be hard linked
+    /// - [`crate::errors::ErrorStatus::OperationNotSupported`] if the filesystem doesn't support hard links
+    SysFSLink = 73,
+    /// Recursively removes a path and everything under it inside the kernel
+    ///
+    /// Takes the path and a maximum depth, a depth of 0 only allows removing a file or an empty directory (the same as [`SyscallTable::SysFSRemovePath`]),
+    /// a depth of 1 allows removing a directory and its direct children and so on.
+    ///
+    /// The whole tree is checked before anything is removed, if it is deeper than the maximum depth errors with [`crate::errors::ErrorStatus::DirectoryNotEmpty`] and nothing is removed.
+    /// Each object is removed as if by [`SyscallTable::SysFSRemovePath`], mount points and drives under the path are never crossed.
+    ///
+    /// Returns the amount of removed objects, if removing an object fails (for example due to missing permissions)
+    /// the removal stops and the error is returned, objects removed before the failure stay removed.
+    SysFSRemoveAll = 75,
+    /// Same as [`SyscallTable::SysFSOpen`] but takes a directory resource first, and resolves relative paths relative to that directory instead of the current working directory
+    ///
+    /// Absolute paths ignore the directory resource, the directory resource can be [`crate::fs::CWD_RESOURCE`] to resolve relative to the current working directory.
+    ///
+    /// Errors with [`crate::errors::ErrorStatus::NotADirectory`] if the directory resource isn't a directory,
+    /// the same applies to every other directory-relative (`*At`) syscall
+    SysFSOpenAt = 60,
+    /// Same as [`SyscallTable::SysFSRemovePath`] but resolves the path relative to a directory resource, see [`SyscallTable::SysFSOpenAt`]
+    SysFSRemovePathAt = 61,
     /// Given a Directory resource, opens a Directory Iterator
     SysFDirIterOpen = 8,
     /// Destroys (closes) an open resource whether it is a file, directory, directory iterator, or any other resource
@@ -35,6 +83,32 @@ pub enum SyscallTable {
     ///
     /// Otherwise, errors with [`NotAFile`]
     SysIORead = 4,
+    /// Performs a vectored write operation on a given resource
+    ///
+    /// Takes the resource, the offset to write at (same as [`SyscallTable::SysIOWrite`]) and a [`crate::ffi::slice::Slice`] of [`crate::ffi::IoSlice`]s,
+    /// the buffers are written in order as if they were a single contiguous buffer, returns the total amount of bytes written.
+    ///
+    /// Same as [`SyscallTable::SysIOWrite`] the write may be short, in that case the buffers after the last written byte are left untouched.
+    SysIOWriteV = 55,
+    /// Performs a vectored read operation on a given resource
+    ///
+    /// Takes the resource, the offset to read from (same as [`SyscallTable::SysIORead`]) and a [`crate::ffi::slice::Slice`] of [`crate::ffi::IoSliceMut`]s,
+    /// the buffers are filled in order as if they were a single contiguous buffer, returns the total amount of bytes read.
+    SysIOReadV = 56,
+    /// Copies a range of bytes from one resource to another inside the kernel without bouncing the data through userspace
+    ///
+    /// Takes the source resource, the source offset, the destination resource, the destination offset and the amount of bytes to copy,
+    /// offsets are interpreted the same as in [`SyscallTable::SysIORead`] and [`SyscallTable::SysIOWrite`], returns the amount of bytes copied.
+    ///
+    /// The copy may be short, for example when reaching the end of the source file, returns 0 if there was nothing to copy.
+    /// Same as [`SyscallTable::SysIOWrite`] writes to a destination file are pending until [`SyscallTable::SysIOSync`] is performed.
+    ///
+    /// Supported (source, destination) pairs are:
+    /// - (file, file): the source and the destination may be the same file as long as the ranges don't overlap, otherwise errors with [`crate::errors::ErrorStatus::InvalidOffset`]
+    /// - (file, connected stream or seqpacket socket): the destination offset is ignored, blocks the same as [`SyscallTable::SysSockSendTo`] unless the socket is non-blocking
+    ///
+    /// Any other pair errors with [`crate::errors::ErrorStatus::UnsupportedResource`].
+    SysIOCopyRange = 59,
     /// Given a set of resources, waits for any of them to become ready for I/O (with specified events), returns the events that occurred causing the thread to wake up.
     /// A single poll entry's layout is defined in [`crate::poll::PollEntry`].
     SysIOPoll = 45,
@@ -42,6 +116,10 @@ pub enum SyscallTable {
     SysFSCreate = 6,
     /// Creates a new directory
     SysFSCreateDir = 7,
+    /// Same as [`SyscallTable::SysFSCreate`] but resolves the path relative to a directory resource, see [`SyscallTable::SysFSOpenAt`]
+    SysFSCreateAt = 62,
+    /// Same as [`SyscallTable::SysFSCreateDir`] but resolves the path relative to a directory resource, see [`SyscallTable::SysFSOpenAt`]
+    SysFSCreateDirAt = 63,
     /// Performs a Sync operation on a given resource
     ///
     /// If the resource is a device, the behavior is device specific.
@@ -50,21 +128,120 @@ pub enum SyscallTable {
     ///
     /// Otherwise, does nothing or errors with [`NotAFile`]
     SysIOSync = 16,
+    /// Like [`SyscallTable::SysIOSync`] but only syncs a byte range of a file with given [`crate::fs::SyncFlags`]
+    ///
+    /// Takes the resource, the flags, the offset and the length of the range, a length of 0 means until the end of the file.
+    ///
+    /// With [`crate::fs::SyncFlags::DATA_ONLY`] metadata that isn't required to read the data back isn't synced,
+    /// with [`crate::fs::SyncFlags::ASYNC`] the syscall returns once the writeback is initiated, a later sync without it waits for the writeback to complete.
+    ///
+    /// If the resource is a device, the behavior is device specific, otherwise errors with [`crate::errors::ErrorStatus::NotAFile`]
+    SysIOSyncRange = 76,
     /// Truncates a file to a given size
     SysIOTruncate = 17,
+    /// Manipulates the allocated storage of a file without writing to it
+    ///
+    /// Takes the file resource, [`crate::fs::FileAllocateMode`], the offset and the length of the range,
+    /// allocations are reflected in [`crate::fs::FileAttr::allocated_blocks`].
+    ///
+    /// Without [`crate::fs::FileAllocateMode::PUNCH_HOLE`] or [`crate::fs::FileAllocateMode::ZERO_RANGE`] the range is preallocated,
+    /// preallocating guarantees that later writes to the range won't fail because of running out of space,
+    /// errors with [`crate::errors::ErrorStatus::NoSpaceLeft`] if there isn't enough space on the drive to allocate the whole range, in that case nothing is allocated.
+    ///
+    /// [`crate::fs::FileAllocateMode::PUNCH_HOLE`] and [`crate::fs::FileAllocateMode::ZERO_RANGE`] cannot be combined, otherwise errors with [`crate::errors::ErrorStatus::InvalidArgument`],
+    /// if the filesystem doesn't support the requested mode errors with [`crate::errors::ErrorStatus::OperationNotSupported`].
+    ///
+    /// Errors with [`crate::errors::ErrorStatus::NotAFile`] if the resource isn't a file
+    SysIOAllocate = 74,
+    /// Acquires or releases an advisory lock on a file resource
+    ///
+    /// Takes the resource, [`crate::fs::FileLockFlags`] and a pointer to a [`crate::fs::FileLockRange`] (null means [`crate::fs::FileLockRange::WHOLE_FILE`]).
+    ///
+    /// Locks are advisory, they don't prevent [`SyscallTable::SysIORead`] or [`SyscallTable::SysIOWrite`], only other lock attempts.
+    /// A lock conflicts with any overlapping lock held through a different open file, an exclusive lock conflicts with every lock while shared locks only conflict with exclusive ones.
+    /// Acquiring a lock over a range already locked through the same open file converts it (shared to exclusive or the other way around) instead of conflicting.
+    ///
+    /// On conflict the calling thread blocks until the lock can be acquired,
+    /// unless [`crate::fs::FileLockFlags::NON_BLOCKING`] was given in that case it errors with [`crate::errors::ErrorStatus::WouldBlock`].
+    ///
+    /// Locks belong to the open file and not to the resource ID:
+    /// - resources duplicated with [`SyscallTable::SysRClone`] or inherited with [`crate::process::SpawnFlags::CLONE_RESOURCES`] share the same locks,
+    ///   any of them can release them
+    /// - the locks are released when the last resource referring to the open file is destroyed with [`SyscallTable::SysRDestroy`] or the owning processes exit,
+    ///   destroying one of many duplicates doesn't release anything
+    /// - opening the same path again creates a new open file that conflicts with the old one
+    ///
+    /// Errors with [`crate::errors::ErrorStatus::NotAFile`] if the resource isn't a file
+    SysFLock = 54,
     /// Sends a Command to a given resource that is a device
     ///
     /// The behavior is device specific.
     ///
     /// Takes 2 arguments: the command (can be as big as size of u16) and the argument (can be as big as size of u64)
     SysIOCommand = 12,
+    /// Gets information about a given resource that is a device, takes a pointer to a [`crate::fs::DeviceInfo`] to fill
+    ///
+    /// Errors with [`crate::errors::ErrorStatus::NotADevice`] if the resource isn't a device
+    SysFDeviceInfo = 72,
     /// Duplicates a given resource, returns a new resource ID pointing to the same resource internally
     ///
     /// Succeeds whether the resource is a file, directory, directory iterator or a device
+    ///
+    /// The new resource shares any state tied to the open file with the old one, such as advisory locks acquired using [`SyscallTable::SysFLock`]
     SysRClone = 26,
     // TODO: remove in favor of FAttrs
     SysFSize = 22,
     SysFAttrs = 24,
+    /// Like [`SyscallTable::SysFAttrs`] but gets the [`crate::fs::FileAttr`] of the object at a path resolved relative to a directory resource without opening it,
+    /// see [`SyscallTable::SysFSOpenAt`]
+    SysFAttrsAt = 64,
+    /// Gets the value of a named extended attribute of a filesystem object
+    ///
+    /// All the extended attributes syscalls take a resource and an optional path [`crate::ffi::str::Str`] first,
+    /// if the path is None the operation applies to the object the resource refers to,
+    /// otherwise the path is resolved relative to the resource the same as [`SyscallTable::SysFSOpenAt`].
+    ///
+    /// Attribute names are UTF-8 strings bounded by [`crate::consts::MAX_NAME_LENGTH`] that are not empty and don't contain a NUL byte,
+    /// otherwise errors with [`crate::errors::ErrorStatus::StrTooLong`] or [`crate::errors::ErrorStatus::InvalidArgument`].
+    /// Attribute values are arbitrary bytes bounded by [`crate::consts::MAX_XATTR_VALUE_LENGTH`].
+    ///
+    /// Errors with [`crate::errors::ErrorStatus::OperationNotSupported`] if the filesystem doesn't support extended attributes.
+    ///
+    /// Takes the attribute name and a buffer to put the value in, returns the value length,
+    /// if the buffer is empty nothing is written and the value length is returned,
+    /// if the buffer is too small to hold the value errors with [`crate::errors::ErrorStatus::TooShort`],
+    /// if the attribute doesn't exist errors with [`crate::errors::ErrorStatus::NoSuchAttribute`].
+    SysFSGetXAttr = 66,
+    /// Sets the value of a named extended attribute of a filesystem object, see [`SyscallTable::SysFSGetXAttr`]
+    ///
+    /// Takes the attribute name, the value and [`crate::fs::XAttrFlags`],
+    /// errors with [`crate::errors::ErrorStatus::AttributeTooLarge`] if the value exceeds [`crate::consts::MAX_XATTR_VALUE_LENGTH`] or the filesystem ran out of space for attributes.
+    SysFSSetXAttr = 67,
+    /// Lists the names of the extended attributes of a filesystem object, see [`SyscallTable::SysFSGetXAttr`]
+    ///
+    /// Takes a buffer to put the names in, each name is followed by a NUL byte, returns the total length of the list,
+    /// the same as [`SyscallTable::SysFSGetXAttr`] an empty buffer only returns the length and a short one errors with [`crate::errors::ErrorStatus::TooShort`].
+    SysFSListXAttr = 68,
+    /// Removes a named extended attribute from a filesystem object, see [`SyscallTable::SysFSGetXAttr`]
+    ///
+    /// Errors with [`crate::errors::ErrorStatus::NoSuchAttribute`] if the attribute doesn't exist.
+    SysFSRemoveXAttr = 69,
+    /// Changes the [`crate::fs::FileMode`] of a filesystem object
+    ///
+    /// Takes a resource and an optional path the same as [`SyscallTable::SysFSGetXAttr`], then the new mode,
+    /// only the owner of the object or root can change its mode, otherwise errors with [`crate::errors::ErrorStatus::MissingPermissions`].
+    ///
+    /// Errors with [`crate::errors::ErrorStatus::InvalidArgument`] if the mode contains bits outside of [`crate::fs::FileMode::ALL`]
+    SysFSChmod = 70,
+    /// Changes the owning user and group of a filesystem object
+    ///
+    /// Takes a resource and an optional path the same as [`SyscallTable::SysFSGetXAttr`], then the new [`crate::fs::Uid`] and [`crate::fs::Gid`],
+    /// either of them can be `UNCHANGED` to keep the current one.
+    ///
+    /// Only root can change the owner, the owner of the object can change the group to the calling process's [`crate::process::ProcessCredentials::gid`],
+    /// otherwise errors with [`crate::errors::ErrorStatus::MissingPermissions`].
+    /// Changing the owner or the group clears [`crate::fs::FileMode::SETUID`] and [`crate::fs::FileMode::SETGID`]
+    SysFSChown = 71,
     SysFGetDirEntry = 23,
     /// Changes the current working directory to the given path
     SysPCHDir = 14,
@@ -77,6 +254,10 @@ pub enum SyscallTable {
     /// Returns the new data break (address space end)
     SysPSbrk = 18,
     /// Spawns a new process
+    ///
+    /// The child inherits the calling process's [`crate::process::ProcessCredentials`],
+    /// except if the executable has [`crate::fs::FileMode::SETUID`] or [`crate::fs::FileMode::SETGID`], which makes the child run as the executable's owner or group respectively.
+    /// Spawning requires the exec bit of the executable's [`crate::fs::FileMode`], otherwise errors with [`crate::errors::ErrorStatus::MissingPermissions`]
     SysPSpawn = 19,
     /// Spawns a thread inside the current process with the given entry point
     SysTSpawn = 29,
@@ -85,6 +266,8 @@ pub enum SyscallTable {
     /// Sleeps the current thread for the given amount of milliseconds, max is [`u64::MAX`]
     SysTSleep = 31,
     /// Waits for a child process with a given PID to exit, cleans it up and returns the exit code
+    ///
+    /// The exit code also describes how the process exited, see [`crate::process::ExitStatus`]
     SysPWait = 11,
     /// Waits for a child thread with a given TID to exit
     SysTWait = 32,
@@ -98,6 +281,97 @@ pub enum SyscallTable {
     SysTFutWait = 34,
     /// Performs a WAKE(addr, n) on the current thread, wakes n threads waiting on the given address
     SysTFutWake = 35,
+    /// Forcefully terminates a process, unlike [`crate::signal::Signal::Terminate`] this cannot be handled, blocked or ignored
+    ///
+    /// Takes a PID and a reason code, the reason is an [`crate::errors::ErrorStatus`] value, usually [`crate::errors::ErrorStatus::ForceTerminated`],
+    /// errors with [`crate::errors::ErrorStatus::InvalidArgument`] if it isn't a known error code.
+    ///
+    /// The target must be the calling process or one of its direct children (the calling process is its [`crate::process::AbiStructures::parent_process_pid`]),
+    /// processes running as [`crate::fs::Uid::ROOT`] can kill any process, otherwise errors with [`crate::errors::ErrorStatus::MissingPermissions`],
+    /// errors with [`crate::errors::ErrorStatus::InvalidPid`] if the process doesn't exist or already exited.
+    ///
+    /// Every thread of the victim is stopped, syscalls it was blocked in are aborted, then it is cleaned up the same as if it exited normally (its resources are destroyed, releasing its locks),
+    /// its exit code as returned by [`SyscallTable::SysPWait`] and [`SyscallTable::SysPTryCleanUp`] is the reason code with [`crate::process::EXIT_KILLED`] set
+    /// (`EXIT_KILLED | reason as u16 as usize`), which no process exiting on its own can have, use [`crate::process::ExitStatus::from_raw`] to decode it
+    SysPKill = 98,
+    /// Forcefully terminates a thread of the calling process, takes a TID and a reason code the same as [`SyscallTable::SysPKill`]
+    ///
+    /// Errors with [`crate::errors::ErrorStatus::InvalidTid`] if the thread doesn't exist or doesn't belong to the calling process.
+    ///
+    /// Syscalls the thread was blocked in are aborted, threads waiting on it with [`SyscallTable::SysTWait`] are woken up,
+    /// if it was the last thread in the process the process exits as if it was killed with [`SyscallTable::SysPKill`] with the same reason
+    SysTKill = 99,
+    /// Gets the credentials of the calling process, takes a pointer to a [`crate::process::ProcessCredentials`] to fill
+    SysPGetCredentials = 100,
+    /// Changes the credentials of the calling process, takes a pointer to a [`crate::process::ProcessCredentials`],
+    /// either field can be [`crate::fs::Uid::UNCHANGED`] (or [`crate::fs::Gid::UNCHANGED`]) to keep the current one.
+    ///
+    /// Processes running as [`crate::fs::Uid::ROOT`] can set any credentials, other processes can only set their current ones,
+    /// otherwise errors with [`crate::errors::ErrorStatus::MissingPermissions`], so a process that drops root cannot regain it.
+    ///
+    /// The change applies to every thread of the process and to processes it spawns afterwards, resources that are already open are unaffected.
+    SysPSetCredentials = 101,
+    /// Registers a process wide userspace handler for hardware faults, takes a pointer to a [`crate::fault::RawFaultHandlerConfig`]
+    ///
+    /// When a thread raises a fault of one of the configured kinds, the kernel invokes the handler on that thread with a [`crate::fault::FaultRecord`] describing the fault,
+    /// on the thread's alternate stack if one was set with [`SyscallTable::SysTSetAltStack`] (required to handle stack overflows into guard pages) or on the current stack otherwise.
+    /// The handler's return address is a kernel provided trampoline that resumes the thread at the address the handler returns,
+    /// if it returns 0, or if the thread faults again while running the handler, the process is terminated the same as if no handler was registered.
+    ///
+    /// Registering a handler replaces the previous one, a config without a handler removes it,
+    /// errors with [`crate::errors::ErrorStatus::NotSupported`] if the config's revision is newer than [`crate::fault::RawFaultHandlerConfig::LATEST_REVISION`]
+    SysPSetFaultHandler = 90,
+    /// Sets the alternate stack of the calling thread, used to run fault handlers registered with [`SyscallTable::SysPSetFaultHandler`]
+    /// and signal handlers registered with [`crate::signal::SignalActionFlags::ON_ALT_STACK`]
+    ///
+    /// Takes an optional [`crate::ffi::slice::Slice`] of bytes describing the stack, None removes the alternate stack,
+    /// the memory must stay mapped and writable while it is set
+    SysTSetAltStack = 91,
+    /// Opens a pollable Resource that receives the faults raised by a process's threads
+    ///
+    /// Takes a PID which can be [`crate::process::SELF_PID`], otherwise it must be a child of the calling process, otherwise errors with [`crate::errors::ErrorStatus::MissingPermissions`].
+    ///
+    /// A [`crate::fault::FaultRecord`] is queued for every fault raised, whether or not it was handled by a fault handler,
+    /// [`SyscallTable::SysIORead`] reads whole records and errors with [`crate::errors::ErrorStatus::TooShort`] if the buffer cannot hold one,
+    /// and [`SyscallTable::SysIOPoll`] reports [`crate::poll::PollEvents::DATA_AVAILABLE`] when a record is queued.
+    /// Once the process exits, reads return the remaining records then [`crate::poll::PollEvents::DISCONNECTED`] is reported.
+    SysPFaultOpen = 92,
+    /// Sets how a signal is handled by the calling process
+    ///
+    /// Takes a [`crate::signal::Signal`], a pointer to the new [`crate::signal::RawSignalAction`] and a pointer to put the old action in, either pointer can be null.
+    ///
+    /// A signal sent to a process is delivered to one of its threads that doesn't block it, if all of them block it the signal stays pending until one unblocks it,
+    /// pending signals of the same number are merged into one.
+    /// Handlers interrupt whatever the thread is doing, a blocking syscall interrupted by a handler returns [`crate::errors::ErrorStatus::ForceTerminated`]
+    /// unless [`crate::signal::SignalActionFlags::RESTART`] is given.
+    ///
+    /// A signal whose action is [`crate::signal::SignalActionKind::Default`] and that [`crate::signal::Signal::terminates_by_default`] terminates the process,
+    /// [`SyscallTable::SysPWait`] and [`SyscallTable::SysPTryCleanUp`] then return the signal number with [`crate::process::EXIT_SIGNALED`] set,
+    /// which [`crate::process::ExitStatus::from_raw`] decodes as [`crate::process::ExitStatus::Signaled`].
+    ///
+    /// Errors with [`crate::errors::ErrorStatus::InvalidArgument`] if the signal is unknown or the action is [`crate::signal::SignalActionKind::Handle`] without a handler
+    SysPSigAction = 93,
+    /// Changes the signal mask of the calling thread, blocked signals stay pending until they are unblocked
+    ///
+    /// Takes a [`crate::signal::SigMaskHow`], a [`crate::signal::SignalSet`] and a pointer to put the old mask in which can be null.
+    /// Spawned threads and processes start with the mask of the thread that spawned them
+    SysTSigMask = 94,
+    /// Sends a [`crate::signal::Signal`] to a process
+    ///
+    /// Takes a PID which can be [`crate::process::SELF_PID`], the target must be the calling process, its parent or one of its children,
+    /// processes running as [`crate::fs::Uid::ROOT`] can signal any process, otherwise errors with [`crate::errors::ErrorStatus::MissingPermissions`].
+    ///
+    /// Errors with [`crate::errors::ErrorStatus::InvalidPid`] if the process doesn't exist
+    SysPSignal = 95,
+    /// Opens a pollable Resource that receives signals, for event loop programs that prefer it over handlers
+    ///
+    /// Takes a [`crate::signal::SignalSet`], signals in the set that are blocked by every thread are queued to the Resource instead of staying pending,
+    /// [`SyscallTable::SysIORead`] reads whole [`crate::signal::SignalInfo`]s and errors with [`crate::errors::ErrorStatus::TooShort`] if the buffer cannot hold one,
+    /// and [`SyscallTable::SysIOPoll`] reports [`crate::poll::PollEvents::DATA_AVAILABLE`] when a signal is queued
+    SysSignalOpen = 96,
+    /// Delivers [`crate::signal::Signal::Alarm`] to the calling process after the given amount of milliseconds, replacing any previously set alarm,
+    /// an amount of 0 cancels the alarm, returns the remaining milliseconds of the previous alarm or 0 if there was none
+    SysPAlarm = 97,
 
     SysShutdown = 20,
     SysReboot = 21,
@@ -105,6 +379,9 @@ pub enum SyscallTable {
     SysUptime = 27,
     /// Maps N pages after a given address to memory that may be shared with a Device or a File
     ///
+    /// Takes a pointer to a [`crate::mem::RawMemMapConfig`] and [`crate::mem::MemMapFlags`],
+    /// the configuration is checked with [`crate::mem::RawMemMapConfig::validate`] and the error is converted to an [`crate::errors::ErrorStatus`]
+    ///
     /// The given address is a just a hint unless specified (not yet implemented) with the flag [`crate::mem::MemMapFlags::FIXED`], in that case unlike mmap in linux you cannot map colliding regions
     /// The address can be null telling the kernel to choose it's own hint
     ///
@@ -112,14 +389,27 @@ pub enum SyscallTable {
     ///
     /// Returns A Resource that tracks that Memory Mapping and the mappings start address,
     /// By default the resource is a global resource meaning it lives as long as the process,
-    /// The resource's lifetime can be thread bound with the flag `F_LOCAL`, when the thread exits the resource will be dropped
+    /// The resource's lifetime can be thread bound with the flag [`crate::mem::MemMapFlags::THREAD_LOCAL`], when the thread exits the resource will be dropped
     ///
-    /// To Manually Drop the resource aside from relaying on lifetimes use [`SyscallTable::SysRDestroy`],
+    /// To Manually Drop the resource aside from relaying on lifetimes use [`SyscallTable::SysRDestroy`], to only release a part of the mapping use [`SyscallTable::SysMemUnmap`],
     /// To Sync the Memory with the associated File or Device you can either destroy it, let it die or use [`SyscallTable::SysIOSync`]
     ///
+    /// Whether writes reach the mapped resource is controlled by [`crate::mem::MemMapFlags::SHARED`] and [`crate::mem::MemMapFlags::PRIVATE`]:
+    /// - with [`crate::mem::MemMapFlags::MAP_RESOURCE`] the mapping is shared by default, writes are visible to every other shared mapping of the resource
+    ///   and are written back to the resource on sync as described above
+    /// - with [`crate::mem::MemMapFlags::PRIVATE`] the resource's pages are copied on the first write, writes never reach the resource or other mappings,
+    ///   syncing such a mapping does nothing, whether changes made to the resource after mapping are visible in pages that weren't written to is unspecified
+    /// - mapping a shared memory resource opened with [`SyscallTable::SysMemShmOpen`] follows the same rules, a private mapping gets a copy on write snapshot
+    /// - without [`crate::mem::MemMapFlags::MAP_RESOURCE`] the memory is always private to the process and both flags are ignored
+    ///
+    /// Giving both [`crate::mem::MemMapFlags::SHARED`] and [`crate::mem::MemMapFlags::PRIVATE`] errors with [`crate::errors::ErrorStatus::InvalidArgument`]
+    ///
     /// Other flags include:
     /// - [crate::mem::MemMapFlags::WRITE]
     /// - [crate::mem::MemMapFlags::DISABLE_EXEC]
+    /// - [crate::mem::MemMapFlags::POPULATE]
+    /// - [crate::mem::MemMapFlags::LARGE_PAGES] and [crate::mem::MemMapFlags::HUGE_PAGES], which change the page size the page counts are in,
+    ///   see [`crate::mem::RawMemMapConfig::page_count`]
     SysMemMap = 36,
     /// Changes premissons and flags of a given user allocated area of memory.
     ///
@@ -127,8 +417,113 @@ pub enum SyscallTable {
     ///
     /// And flags [`crate::mem::MemFlags`] to use as the new flags of memory protection.
     SysMemProtect = 48,
+    /// Gets the page sizes supported by the kernel, takes a pointer to a [`crate::mem::PageSizes`] to fill
+    ///
+    /// The same information is passed to processes at startup in [`crate::process::AbiStructures::page_sizes`]
+    SysMemPageSizes = 78,
+    /// Unmaps a part of a memory mapping created with [`SyscallTable::SysMemMap`]
+    ///
+    /// Takes the Resource that describes the memory map, the start address of the range to unmap and a page count,
+    /// the range must be aligned to the mapping's page size and inside the mapping, otherwise errors with [`crate::errors::ErrorStatus::InvalidArgument`].
+    ///
+    /// The unmapped pages are synced first the same as destroying the mapping would, accessing them afterwards is a fault.
+    /// The resource keeps tracking the rest of the mapping, even if unmapping the middle of the mapping split it in two,
+    /// once every page is unmapped the resource is destroyed.
+    ///
+    /// The guard pages of the original mapping stay reserved until the resource is destroyed, the unmapped range can be reused by later mappings.
+    SysMemUnmap = 79,
+    /// Resizes a memory mapping created with [`SyscallTable::SysMemMap`], moving it if necessary and allowed
+    ///
+    /// Takes the Resource that describes the memory map, the new page count, [`crate::mem::MemRemapFlags`] and a new address (ignored unless [`crate::mem::MemRemapFlags::FIXED`] is given),
+    /// returns the new start address of the mapping, the resource keeps tracking the mapping.
+    ///
+    /// Shrinking always happens in place and releases the pages at the end of the mapping, the same as [`SyscallTable::SysMemUnmap`].
+    ///
+    /// Growing happens in place if the pages after the mapping (past its guard pages) are free,
+    /// otherwise the mapping is moved to a new area if [`crate::mem::MemRemapFlags::MAY_MOVE`] is given, keeping its contents without copying them,
+    /// otherwise errors with [`crate::errors::ErrorStatus::MMapError`].
+    ///
+    /// A mapping created with [`crate::mem::MemMapFlags::FIXED`] is never moved unless [`crate::mem::MemRemapFlags::FIXED`] is given explicitly,
+    /// in that case [`crate::mem::MemRemapFlags::MAY_MOVE`] is required and the new area must not collide with any existing mapping, otherwise errors with [`crate::errors::ErrorStatus::MMapError`].
+    ///
+    /// Errors with [`crate::errors::ErrorStatus::InvalidArgument`] if the new page count is zero (use [`SyscallTable::SysRDestroy`] instead),
+    /// or if the mapping was split by [`SyscallTable::SysMemUnmap`]
+    SysMemRemap = 80,
+    /// Advises the kernel about how a range of a memory mapping is going to be used, given a [`crate::mem::MemAdvice`]
+    ///
+    /// Takes the Resource that describes the memory map, an address inside the mapping and a page count to describe the range,
+    /// the address can be null to apply the advice to the whole mapping, the same as [`SyscallTable::SysMemSync`].
+    ///
+    /// The advice never changes the mapping itself, the address range stays reserved and the resource keeps tracking it.
+    /// Only [`crate::mem::MemAdvice::DontNeed`] and [`crate::mem::MemAdvice::Free`] affect the contents of the memory, other advices are hints that may be ignored.
+    ///
+    /// Errors with [`crate::errors::ErrorStatus::InvalidArgument`] if the range isn't inside the mapping or the advice is unknown,
+    /// and with [`crate::errors::ErrorStatus::UnsupportedResource`] if the advice doesn't apply to that kind of mapping,
+    /// such as [`crate::mem::MemAdvice::Free`] on a resource mapping
+    SysMemAdvise = 81,
+    /// Locks a range of a memory mapping into physical memory
+    ///
+    /// Takes the Resource that describes the memory map, an address inside the mapping and a page count to describe the range,
+    /// the address can be null to lock the whole mapping, the same as [`SyscallTable::SysMemSync`].
+    ///
+    /// Once this returns every page in the range is populated and resident, accessing it never faults,
+    /// and its physical backing stays the same (it is never swapped out, migrated or copied) until the range is unlocked,
+    /// writable private resource mappings have their pages copied before they are locked.
+    ///
+    /// Locks don't stack, locking a page twice then unlocking it once unlocks it, pages are unlocked when they are unmapped or the mapping is destroyed.
+    ///
+    /// Each process has a limit on the amount of locked memory, processes running as [`crate::fs::Uid::ROOT`] aren't limited,
+    /// errors with [`crate::errors::ErrorStatus::MissingPermissions`] if the process isn't allowed to lock any memory (the limit is zero),
+    /// and with [`crate::errors::ErrorStatus::OutOfMemory`] if locking the range would exceed the limit or there isn't enough physical memory,
+    /// in both cases nothing is locked.
+    SysMemLock = 82,
+    /// Unlocks a range of a memory mapping locked with [`SyscallTable::SysMemLock`], takes the same arguments
+    ///
+    /// Unlocking pages that aren't locked does nothing.
+    SysMemUnlock = 83,
+    /// Gets the physical addresses backing a range of a memory mapping, for use by privileged userspace drivers (for example to set up DMA)
+    ///
+    /// Takes the Resource that describes the memory map, the start address of the range, a page count and a [`crate::ffi::slice::Slice`] of [`usize`]s,
+    /// puts the physical address of each page in the range in the slice in order, returns the amount of addresses written.
+    ///
+    /// The addresses are only guaranteed to stay valid while the pages are locked, so every page in the range must be locked with [`SyscallTable::SysMemLock`],
+    /// otherwise errors with [`crate::errors::ErrorStatus::InvalidArgument`].
+    ///
+    /// Errors with [`crate::errors::ErrorStatus::MissingPermissions`] if the process isn't running as [`crate::fs::Uid::ROOT`],
+    /// and with [`crate::errors::ErrorStatus::TooShort`] if the slice cannot hold an address for each page
+    SysMemPhysAddrs = 84,
+    /// Enumerates the memory mappings of a process, like `/proc/self/maps`
+    ///
+    /// Takes a PID, a [`crate::ffi::slice::Slice`] of [`crate::mem::MemMapInfo`]s and the amount of mappings to skip,
+    /// fills the slice with the mappings sorted by start address starting after the skipped ones, returns the total amount of mappings in the process,
+    /// so the slice may be refilled with a larger skip if it was too small to hold all of them.
+    ///
+    /// The PID can be [`crate::process::SELF_PID`] for the calling process, otherwise it must be a child of the calling process
+    /// (the calling process is its [`crate::process::AbiStructures::parent_process_pid`]), processes running as [`crate::fs::Uid::ROOT`] can inspect any process,
+    /// otherwise errors with [`crate::errors::ErrorStatus::MissingPermissions`].
+    ///
+    /// Errors with [`crate::errors::ErrorStatus::InvalidPid`] if the process doesn't exist.
+    /// The result is a snapshot, mappings may change right after it is taken unless the process is stopped
+    SysMemMappings = 85,
+    /// Gets the memory usage of a process, takes a PID and a pointer to a [`crate::mem::ProcessMemStats`] to fill
+    ///
+    /// The PID can be [`crate::process::SELF_PID`], the same permission checks as [`SyscallTable::SysMemMappings`] apply
+    SysPMemStats = 88,
+    /// Gets the system-wide memory usage, takes a pointer to a [`crate::mem::SystemMemStats`] to fill
+    SysMemStats = 89,
+    /// Syncs a memory mapping created with [`crate::mem::MemMapFlags::MAP_RESOURCE`] with the mapped resource, given [`crate::mem::MemSyncFlags`]
+    ///
+    /// Takes the Resource that describes the memory map, an address inside the mapping and a page count to describe the range to sync,
+    /// the address can be null to sync the whole mapping.
+    ///
+    /// Unlike [`SyscallTable::SysIOSync`] on the mapping resource, this doesn't necessarily sync the resource itself,
+    /// combine it with [`SyscallTable::SysIOSync`] or [`SyscallTable::SysIOSyncRange`] on the mapped resource to make the writes durable.
+    ///
+    /// Errors with [`crate::errors::ErrorStatus::InvalidArgument`] if the range isn't inside the mapping or if the flags don't contain exactly one of
+    /// [`crate::mem::MemSyncFlags::SYNC`] or [`crate::mem::MemSyncFlags::ASYNC`], does nothing for mappings that aren't associated with a resource
+    SysMemSync = 77,
     /// Create a Shared Memory Descriptor, returning a key that points to it,
-    /// The life time of that descriptor is bound to the calling process or the thread if a flag was specified.
+    /// The life time of that descriptor is bound to the calling process or the thread if [`crate::mem::ShmFlags::THREAD_LOCAL`] was specified.
     ///
     /// The returned Key can then be opened from another process using [`SyscallTable::SysMemShmOpen`] and then [`SyscallTable::SysMemMap`]ped,
     /// instead of calling [`SysMemShmOpen`] afterwards this returns an Optional Resource ID that can be mapped directly using [`SysMemMap`] from the calling process,
@@ -139,8 +534,41 @@ pub enum SyscallTable {
     /// Creates a Resource that can be [`SyscallTable::SysMemMap`]ped to a Shared Memory Descriptor,
     /// Takes in a key that was created using [`SyscallTable::SysMemShmCreate`].
     ///
-    /// The lifetime of the Resource is bound to the process or a single thread if a flag was specified
+    /// The lifetime of the Resource is bound to the process or a single thread if [`crate::mem::ShmFlags::THREAD_LOCAL`] was specified,
+    /// with [`crate::mem::ShmFlags::READ_ONLY`] the Resource can only be mapped without [`crate::mem::MemMapFlags::WRITE`]
     SysMemShmOpen = 43,
+    /// Opens a named Shared Memory Descriptor so that unrelated processes can rendezvous on it without passing keys around, returns a Resource the same as [`SyscallTable::SysMemShmOpen`]
+    ///
+    /// Takes the name [`crate::ffi::str::Str`], a page count, [`crate::mem::ShmFlags`] and a [`crate::fs::FileMode`],
+    /// names are bounded by [`crate::consts::MAX_NAME_LENGTH`] and are global to the system.
+    ///
+    /// If no descriptor with that name exists and [`crate::mem::ShmFlags::CREATE`] is given a new one with the given page count is created,
+    /// otherwise errors with [`crate::errors::ErrorStatus::NoSuchAFileOrDirectory`], the page count and the mode are ignored when opening an existing descriptor.
+    /// With [`crate::mem::ShmFlags::EXCLUSIVE`] errors with [`crate::errors::ErrorStatus::AlreadyExists`] if the descriptor already exists, so the caller knows it owns it.
+    ///
+    /// A created descriptor is owned by the calling process's [`crate::process::ProcessCredentials`] and gets the given mode,
+    /// only the read and write bits are used and other bits error with [`crate::errors::ErrorStatus::InvalidArgument`],
+    /// [`crate::fs::FileMode::DEFAULT_FILE`] is a reasonable default.
+    /// Opening an existing descriptor is checked against its owner and mode the same as [`SyscallTable::SysFSOpen`]:
+    /// the read bit is required, and the write bit is required unless [`crate::mem::ShmFlags::READ_ONLY`] is given,
+    /// otherwise errors with [`crate::errors::ErrorStatus::MissingPermissions`], processes running as [`crate::fs::Uid::ROOT`] bypass the checks.
+    ///
+    /// The name lives as long as the descriptor, so it is released once every process/thread that opened it dropped its Resources.
+    SysMemShmOpenNamed = 86,
+    /// Given a Resource opened with [`SyscallTable::SysMemShmOpen`] or [`SyscallTable::SysMemShmOpenNamed`], returns the size of the Shared Memory Descriptor in bytes
+    SysMemShmSize = 87,
+    /// Creates an anonymous memory backed file resource that has no name in the VFS, takes an optional debug name [`crate::ffi::str::Str`] and [`crate::mem::MemFileFlags`]
+    ///
+    /// The debug name is bounded by [`crate::consts::MAX_NAME_LENGTH`] and doesn't have to be unique, it is only used to identify the resource in diagnostics.
+    ///
+    /// The returned resource acts as a regular file that starts empty:
+    /// - [`SyscallTable::SysIOTruncate`] sets its size, growing zero fills and shrinking discards the memory past the new size
+    /// - [`SyscallTable::SysIORead`], [`SyscallTable::SysIOWrite`] and [`SyscallTable::SysFAttrs`] work as they do on files, [`SyscallTable::SysIOSync`] does nothing
+    /// - it can be mapped with [`SyscallTable::SysMemMap`] using [`crate::mem::MemMapFlags::MAP_RESOURCE`], every mapping of it shares the same memory
+    ///
+    /// The memory lives as long as any resource or mapping refers to it, so it can be shared with other processes by passing it in [`crate::process::ProcessStdio`],
+    /// inheriting it with [`crate::process::SpawnFlags::CLONE_RESOURCES`] or duplicating it with [`SyscallTable::SysRClone`].
+    SysMemFileCreate = 65,
     // Sockets
     /// Creates a Socket Descriptor resource
     ///
@@ -165,6 +593,16 @@ pub enum SyscallTable {
     SysSockSendTo = 46,
     /// Given a socket, receive data from it with given flags then get the address that we received from if possible.
     SysSockRecvFrom = 47,
+    /// Like [`SyscallTable::SysSockSendTo`] but gathers the data to send from multiple buffers,
+    /// takes in a socket, a pointer to a [`crate::sockets::SockSendMsgHeader`] describing the target address and the buffers, and [`crate::sockets::SockMsgFlags`]
+    ///
+    /// The buffers are sent as a single message for sockets that preserves message boundaries, returns the amount of bytes sent.
+    SysSockSendMsg = 57,
+    /// Like [`SyscallTable::SysSockRecvFrom`] but scatters the received data into multiple buffers,
+    /// takes in a socket, a pointer to a [`crate::sockets::SockRecvMsgHeader`] describing where to put the source address and the buffers, and [`crate::sockets::SockMsgFlags`]
+    ///
+    /// Returns the amount of bytes received, and sets [`crate::sockets::SockMsgHeader::addr_len`] to the actual length of the source address if one was requested.
+    SysSockRecvMsg = 58,
     /// Allocates a single new pair of Mother VTTY interface and a child VTTY Interface.
     /// TODO: Write VTTY docs.
     SysVTTYAlloc = 44,
@@ -187,7 +625,7 @@ pub enum SyscallTable {
 
 // sadly we cannot use any proc macros here because this crate is used by the libstd port and more, they don't happen to like proc macros...
 /// When a new syscall is added, add to this number, and use the old value as the syscall number
-const NEXT_SYSCALL_NUM: u16 = 54;
+const NEXT_SYSCALL_NUM: u16 = 102;
 
 impl TryFrom<u16> for SyscallTable {
     type Error = ();